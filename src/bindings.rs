@@ -1503,6 +1503,255 @@ pub mod supabase {
             }
         }
         #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod time {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type TimeError = _rt::String;
+            pub type TimeResult = Result<i64, TimeError>;
+            #[allow(unused_unsafe, clippy::all)]
+            /// get seconds since Unix epoch
+            pub fn epoch_secs() -> i64 {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "supabase:wrappers/time@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "epoch-secs"]
+                        fn wit_import0() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    ret
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// parse RFC3339 string to microseconds since Unix epoch
+            pub fn parse_from_rfc3339(s: &str) -> TimeResult {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 8 + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 8
+                            + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = s;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "supabase:wrappers/time@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "parse-from-rfc3339"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result8 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = *ptr1.add(8).cast::<i64>();
+                                l4
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l5 = *ptr1.add(8).cast::<*mut u8>();
+                                let l6 = *ptr1
+                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len7 = l6;
+                                let bytes7 = _rt::Vec::from_raw_parts(
+                                    l5.cast(),
+                                    len7,
+                                    len7,
+                                );
+                                _rt::string_lift(bytes7)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result8
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// parse string from an user-specified format to microseconds since Unix epoch
+            pub fn parse_from_str(s: &str, fmt: &str) -> TimeResult {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 8 + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 8
+                            + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = s;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec1 = fmt;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "supabase:wrappers/time@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "parse-from-str"]
+                        fn wit_import3(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import3(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import3(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1, ptr2)
+                    };
+                    let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                    let result9 = match l4 {
+                        0 => {
+                            let e = {
+                                let l5 = *ptr2.add(8).cast::<i64>();
+                                l5
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l6 = *ptr2.add(8).cast::<*mut u8>();
+                                let l7 = *ptr2
+                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len8 = l7;
+                                let bytes8 = _rt::Vec::from_raw_parts(
+                                    l6.cast(),
+                                    len8,
+                                    len8,
+                                );
+                                _rt::string_lift(bytes8)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result9
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// convert microseconds since Unix epoch to RFC3339 string
+            pub fn epoch_ms_to_rfc3339(msecs: i64) -> Result<_rt::String, TimeError> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "supabase:wrappers/time@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "epoch-ms-to-rfc3339"]
+                        fn wit_import1(_: i64, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: i64, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(_rt::as_i64(&msecs), ptr0) };
+                    let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                    let result9 = match l2 {
+                        0 => {
+                            let e = {
+                                let l3 = *ptr0
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l4 = *ptr0
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len5 = l4;
+                                let bytes5 = _rt::Vec::from_raw_parts(
+                                    l3.cast(),
+                                    len5,
+                                    len5,
+                                );
+                                _rt::string_lift(bytes5)
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l6 = *ptr0
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l7 = *ptr0
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len8 = l7;
+                                let bytes8 = _rt::Vec::from_raw_parts(
+                                    l6.cast(),
+                                    len8,
+                                    len8,
+                                );
+                                _rt::string_lift(bytes8)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result9
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// sleep for a while
+            pub fn sleep(millis: u64) -> () {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "supabase:wrappers/time@0.2.0")]
+                    unsafe extern "C" {
+                        #[link_name = "sleep"]
+                        fn wit_import0(_: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i64) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import0(_rt::as_i64(&millis)) };
+                }
+            }
+        }
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
         pub mod types {
             #[used]
             #[doc(hidden)]
@@ -5784,9 +6033,9 @@ pub(crate) use __export_corrently_fdw_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3662] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xca\x1b\x01A\x02\x01\
-A\x10\x01B\x16\x01o\x02ss\x01p\0\x04\0\x07headers\x03\0\x01\x01q\x05\x03get\0\0\x04\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3887] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xab\x1d\x01A\x02\x01\
+A\x12\x01B\x16\x01o\x02ss\x01p\0\x04\0\x07headers\x03\0\x01\x01q\x05\x03get\0\0\x04\
 post\0\0\x03put\0\0\x05patch\0\0\x06delete\0\0\x04\0\x06method\x03\0\x03\x01r\x04\
 \x06method\x04\x03urls\x07headers\x02\x04bodys\x04\0\x07request\x03\0\x05\x01r\x04\
 \x03urls\x0bstatus-code{\x07headers\x02\x04bodys\x04\0\x08response\x03\0\x07\x01\
@@ -5799,73 +6048,78 @@ es\0\0\x07rows-in\0\0\x08rows-out\0\0\x08bytes-in\0\0\x09bytes-out\0\0\x04\0\x06
 metric\x03\0\x02\x01@\x03\x08fdw-names\x06metric\x03\x03incx\x01\0\x04\0\x09inc-\
 stats\x01\x04\x01@\x01\x08fdw-names\0\x01\x04\0\x0cget-metadata\x01\x05\x01@\x02\
 \x08fdw-names\x08metadata\x01\x01\0\x04\0\x0cset-metadata\x01\x06\x03\0\x1dsupab\
-ase:wrappers/stats@0.2.0\x05\x01\x01Bv\x01q\x0f\x04bool\0\0\x02i8\0\0\x03i16\0\0\
-\x03f32\0\0\x03i32\0\0\x03f64\0\0\x03i64\0\0\x07numeric\0\0\x06string\0\0\x04dat\
-e\0\0\x09timestamp\0\0\x0btimestamptz\0\0\x04json\0\0\x04uuid\0\0\x05other\x01s\0\
-\x04\0\x08type-oid\x03\0\0\x01q\x0f\x04bool\x01\x7f\0\x02i8\x01~\0\x03i16\x01|\0\
-\x03f32\x01v\0\x03i32\x01z\0\x03f64\x01u\0\x03i64\x01x\0\x07numeric\x01u\0\x06st\
-ring\x01s\0\x04date\x01x\0\x09timestamp\x01x\0\x0btimestamptz\x01x\0\x04json\x01\
-s\0\x04uuid\x01s\0\x05other\x01s\0\x04\0\x04cell\x03\0\x02\x04\0\x03row\x03\x01\x04\
-\0\x06column\x03\x01\x01p\x03\x01q\x02\x04cell\x01\x03\0\x05array\x01\x06\0\x04\0\
-\x05value\x03\0\x07\x01r\x02\x02idy\x08type-oidy\x04\0\x05param\x03\0\x09\x04\0\x04\
-qual\x03\x01\x04\0\x04sort\x03\x01\x04\0\x05limit\x03\x01\x01q\x04\x06server\0\0\
-\x05table\0\0\x0dimport-schema\0\0\x05other\x01s\0\x04\0\x0coptions-type\x03\0\x0e\
-\x04\0\x07options\x03\x01\x01q\x03\x03all\0\0\x08limit-to\0\0\x06except\0\0\x04\0\
-\x12import-schema-type\x03\0\x11\x01ps\x01r\x05\x0bserver-names\x0dremote-schema\
-s\x0clocal-schemas\x09list-type\x12\x0atable-list\x13\x04\0\x1aimport-foreign-sc\
-hema-stmt\x03\0\x14\x04\0\x07context\x03\x01\x01s\x04\0\x09fdw-error\x03\0\x17\x01\
-j\0\x01\x18\x04\0\x0afdw-result\x03\0\x19\x01i\x04\x01@\0\0\x1b\x04\0\x10[constr\
-uctor]row\x01\x1c\x01h\x04\x01@\x01\x04self\x1d\0\x13\x04\0\x10[method]row.cols\x01\
-\x1e\x01k\x03\x01p\x1f\x01@\x01\x04self\x1d\0\x20\x04\0\x11[method]row.cells\x01\
-!\x01@\x02\x04self\x1d\x04cell\x1f\x01\0\x04\0\x10[method]row.push\x01\"\x01i\x05\
-\x01@\x01\x05indexy\0#\x04\0\x13[constructor]column\x01$\x01h\x05\x01@\x01\x04se\
-lf%\0s\x04\0\x13[method]column.name\x01&\x01@\x01\x04self%\0y\x04\0\x12[method]c\
-olumn.num\x01'\x01@\x01\x04self%\0\x01\x04\0\x17[method]column.type-oid\x01(\x01\
-i\x0b\x01@\x01\x05indexy\0)\x04\0\x11[constructor]qual\x01*\x01h\x0b\x01@\x01\x04\
-self+\0s\x04\0\x12[method]qual.field\x01,\x04\0\x15[method]qual.operator\x01,\x01\
-@\x01\x04self+\0\x08\x04\0\x12[method]qual.value\x01-\x01@\x01\x04self+\0\x7f\x04\
-\0\x13[method]qual.use-or\x01.\x01k\x0a\x01@\x01\x04self+\0/\x04\0\x12[method]qu\
-al.param\x010\x04\0\x14[method]qual.deparse\x01,\x01i\x0c\x01@\x01\x05indexy\01\x04\
-\0\x11[constructor]sort\x012\x01h\x0c\x01@\x01\x04self3\0s\x04\0\x12[method]sort\
-.field\x014\x01@\x01\x04self3\0y\x04\0\x15[method]sort.field-no\x015\x01@\x01\x04\
-self3\0\x7f\x04\0\x15[method]sort.reversed\x016\x04\0\x18[method]sort.nulls-firs\
-t\x016\x01ks\x01@\x01\x04self3\07\x04\0\x14[method]sort.collate\x018\x04\0\x14[m\
-ethod]sort.deparse\x014\x04\0![method]sort.deparse-with-collate\x014\x01i\x0d\x01\
-@\0\09\x04\0\x12[constructor]limit\x01:\x01h\x0d\x01@\x01\x04self;\0x\x04\0\x13[\
-method]limit.count\x01<\x04\0\x14[method]limit.offset\x01<\x01@\x01\x04self;\0s\x04\
-\0\x15[method]limit.deparse\x01=\x01i\x10\x01@\x01\x0coptions-type\x0f\0>\x04\0\x14\
-[constructor]options\x01?\x01h\x10\x01@\x02\x04self\xc0\0\x03keys\07\x04\0\x13[m\
-ethod]options.get\x01A\x01j\x01s\x01\x18\x01@\x02\x04self\xc0\0\x03keys\0\xc2\0\x04\
-\0\x17[method]options.require\x01C\x01@\x03\x04self\xc0\0\x03keys\x07defaults\0s\
-\x04\0\x1a[method]options.require-or\x01D\x01i\x16\x01@\0\0\xc5\0\x04\0\x14[cons\
-tructor]context\x01F\x01h\x16\x01@\x02\x04self\xc7\0\x0coptions-type\x0f\0>\x04\0\
-\x1b[method]context.get-options\x01H\x01p)\x01@\x01\x04self\xc7\0\0\xc9\0\x04\0\x19\
-[method]context.get-quals\x01J\x01p#\x01@\x01\x04self\xc7\0\0\xcb\0\x04\0\x1b[me\
-thod]context.get-columns\x01L\x01p1\x01@\x01\x04self\xc7\0\0\xcd\0\x04\0\x19[met\
-hod]context.get-sorts\x01N\x01k9\x01@\x01\x04self\xc7\0\0\xcf\0\x04\0\x19[method\
-]context.get-limit\x01P\x03\0\x1dsupabase:wrappers/types@0.2.0\x05\x02\x02\x03\0\
-\x02\x04cell\x01B\x0d\x02\x03\x02\x01\x03\x04\0\x04cell\x03\0\0\x01@\x01\x03msgs\
-\x01\0\x04\0\x0breport-info\x01\x02\x04\0\x0dreport-notice\x01\x02\x04\0\x0erepo\
-rt-warning\x01\x02\x04\0\x0creport-error\x01\x02\x01k\x01\x01@\x01\x04cell\x03\0\
-s\x04\0\x0ecell-to-string\x01\x04\x01ks\x01@\x01\x09secret-ids\0\x05\x04\0\x10ge\
-t-vault-secret\x01\x06\x03\0\x1dsupabase:wrappers/utils@0.2.0\x05\x04\x02\x03\0\x02\
-\x03row\x02\x03\0\x02\x1aimport-foreign-schema-stmt\x02\x03\0\x02\x07context\x02\
-\x03\0\x02\x09fdw-error\x02\x03\0\x02\x0afdw-result\x01B%\x02\x03\x02\x01\x03\x04\
-\0\x04cell\x03\0\0\x02\x03\x02\x01\x05\x04\0\x03row\x03\0\x02\x02\x03\x02\x01\x06\
-\x04\0\x1aimport-foreign-schema-stmt\x03\0\x04\x02\x03\x02\x01\x07\x04\0\x07cont\
-ext\x03\0\x06\x02\x03\x02\x01\x08\x04\0\x09fdw-error\x03\0\x08\x02\x03\x02\x01\x09\
-\x04\0\x0afdw-result\x03\0\x0a\x01@\0\0s\x04\0\x18host-version-requirement\x01\x0c\
-\x01h\x07\x01@\x01\x03ctx\x0d\0\x0b\x04\0\x04init\x01\x0e\x04\0\x0abegin-scan\x01\
-\x0e\x01h\x03\x01ky\x01j\x01\x10\x01\x09\x01@\x02\x03ctx\x0d\x03row\x0f\0\x11\x04\
-\0\x09iter-scan\x01\x12\x04\0\x07re-scan\x01\x0e\x04\0\x08end-scan\x01\x0e\x04\0\
-\x0cbegin-modify\x01\x0e\x01@\x02\x03ctx\x0d\x03row\x0f\0\x0b\x04\0\x06insert\x01\
-\x13\x01@\x03\x03ctx\x0d\x05rowid\x01\x07new-row\x0f\0\x0b\x04\0\x06update\x01\x14\
-\x01@\x02\x03ctx\x0d\x05rowid\x01\0\x0b\x04\0\x06delete\x01\x15\x04\0\x0aend-mod\
-ify\x01\x0e\x01ps\x01j\x01\x16\x01\x09\x01@\x02\x03ctx\x0d\x04stmt\x05\0\x17\x04\
-\0\x15import-foreign-schema\x01\x18\x04\0\x20supabase:wrappers/routines@0.2.0\x05\
-\x0a\x04\03powabase:supabase-fdw-corrently/corrently-fdw@0.2.1\x04\0\x0b\x13\x01\
-\0\x0dcorrently-fdw\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compo\
-nent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+ase:wrappers/stats@0.2.0\x05\x01\x01B\x0f\x01s\x04\0\x0atime-error\x03\0\0\x01j\x01\
+x\x01\x01\x04\0\x0btime-result\x03\0\x02\x01@\0\0x\x04\0\x0aepoch-secs\x01\x04\x01\
+@\x01\x01ss\0\x03\x04\0\x12parse-from-rfc3339\x01\x05\x01@\x02\x01ss\x03fmts\0\x03\
+\x04\0\x0eparse-from-str\x01\x06\x01j\x01s\x01\x01\x01@\x01\x05msecsx\0\x07\x04\0\
+\x13epoch-ms-to-rfc3339\x01\x08\x01@\x01\x06millisw\x01\0\x04\0\x05sleep\x01\x09\
+\x03\0\x1csupabase:wrappers/time@0.2.0\x05\x02\x01Bv\x01q\x0f\x04bool\0\0\x02i8\0\
+\0\x03i16\0\0\x03f32\0\0\x03i32\0\0\x03f64\0\0\x03i64\0\0\x07numeric\0\0\x06stri\
+ng\0\0\x04date\0\0\x09timestamp\0\0\x0btimestamptz\0\0\x04json\0\0\x04uuid\0\0\x05\
+other\x01s\0\x04\0\x08type-oid\x03\0\0\x01q\x0f\x04bool\x01\x7f\0\x02i8\x01~\0\x03\
+i16\x01|\0\x03f32\x01v\0\x03i32\x01z\0\x03f64\x01u\0\x03i64\x01x\0\x07numeric\x01\
+u\0\x06string\x01s\0\x04date\x01x\0\x09timestamp\x01x\0\x0btimestamptz\x01x\0\x04\
+json\x01s\0\x04uuid\x01s\0\x05other\x01s\0\x04\0\x04cell\x03\0\x02\x04\0\x03row\x03\
+\x01\x04\0\x06column\x03\x01\x01p\x03\x01q\x02\x04cell\x01\x03\0\x05array\x01\x06\
+\0\x04\0\x05value\x03\0\x07\x01r\x02\x02idy\x08type-oidy\x04\0\x05param\x03\0\x09\
+\x04\0\x04qual\x03\x01\x04\0\x04sort\x03\x01\x04\0\x05limit\x03\x01\x01q\x04\x06\
+server\0\0\x05table\0\0\x0dimport-schema\0\0\x05other\x01s\0\x04\0\x0coptions-ty\
+pe\x03\0\x0e\x04\0\x07options\x03\x01\x01q\x03\x03all\0\0\x08limit-to\0\0\x06exc\
+ept\0\0\x04\0\x12import-schema-type\x03\0\x11\x01ps\x01r\x05\x0bserver-names\x0d\
+remote-schemas\x0clocal-schemas\x09list-type\x12\x0atable-list\x13\x04\0\x1aimpo\
+rt-foreign-schema-stmt\x03\0\x14\x04\0\x07context\x03\x01\x01s\x04\0\x09fdw-erro\
+r\x03\0\x17\x01j\0\x01\x18\x04\0\x0afdw-result\x03\0\x19\x01i\x04\x01@\0\0\x1b\x04\
+\0\x10[constructor]row\x01\x1c\x01h\x04\x01@\x01\x04self\x1d\0\x13\x04\0\x10[met\
+hod]row.cols\x01\x1e\x01k\x03\x01p\x1f\x01@\x01\x04self\x1d\0\x20\x04\0\x11[meth\
+od]row.cells\x01!\x01@\x02\x04self\x1d\x04cell\x1f\x01\0\x04\0\x10[method]row.pu\
+sh\x01\"\x01i\x05\x01@\x01\x05indexy\0#\x04\0\x13[constructor]column\x01$\x01h\x05\
+\x01@\x01\x04self%\0s\x04\0\x13[method]column.name\x01&\x01@\x01\x04self%\0y\x04\
+\0\x12[method]column.num\x01'\x01@\x01\x04self%\0\x01\x04\0\x17[method]column.ty\
+pe-oid\x01(\x01i\x0b\x01@\x01\x05indexy\0)\x04\0\x11[constructor]qual\x01*\x01h\x0b\
+\x01@\x01\x04self+\0s\x04\0\x12[method]qual.field\x01,\x04\0\x15[method]qual.ope\
+rator\x01,\x01@\x01\x04self+\0\x08\x04\0\x12[method]qual.value\x01-\x01@\x01\x04\
+self+\0\x7f\x04\0\x13[method]qual.use-or\x01.\x01k\x0a\x01@\x01\x04self+\0/\x04\0\
+\x12[method]qual.param\x010\x04\0\x14[method]qual.deparse\x01,\x01i\x0c\x01@\x01\
+\x05indexy\01\x04\0\x11[constructor]sort\x012\x01h\x0c\x01@\x01\x04self3\0s\x04\0\
+\x12[method]sort.field\x014\x01@\x01\x04self3\0y\x04\0\x15[method]sort.field-no\x01\
+5\x01@\x01\x04self3\0\x7f\x04\0\x15[method]sort.reversed\x016\x04\0\x18[method]s\
+ort.nulls-first\x016\x01ks\x01@\x01\x04self3\07\x04\0\x14[method]sort.collate\x01\
+8\x04\0\x14[method]sort.deparse\x014\x04\0![method]sort.deparse-with-collate\x01\
+4\x01i\x0d\x01@\0\09\x04\0\x12[constructor]limit\x01:\x01h\x0d\x01@\x01\x04self;\
+\0x\x04\0\x13[method]limit.count\x01<\x04\0\x14[method]limit.offset\x01<\x01@\x01\
+\x04self;\0s\x04\0\x15[method]limit.deparse\x01=\x01i\x10\x01@\x01\x0coptions-ty\
+pe\x0f\0>\x04\0\x14[constructor]options\x01?\x01h\x10\x01@\x02\x04self\xc0\0\x03\
+keys\07\x04\0\x13[method]options.get\x01A\x01j\x01s\x01\x18\x01@\x02\x04self\xc0\
+\0\x03keys\0\xc2\0\x04\0\x17[method]options.require\x01C\x01@\x03\x04self\xc0\0\x03\
+keys\x07defaults\0s\x04\0\x1a[method]options.require-or\x01D\x01i\x16\x01@\0\0\xc5\
+\0\x04\0\x14[constructor]context\x01F\x01h\x16\x01@\x02\x04self\xc7\0\x0coptions\
+-type\x0f\0>\x04\0\x1b[method]context.get-options\x01H\x01p)\x01@\x01\x04self\xc7\
+\0\0\xc9\0\x04\0\x19[method]context.get-quals\x01J\x01p#\x01@\x01\x04self\xc7\0\0\
+\xcb\0\x04\0\x1b[method]context.get-columns\x01L\x01p1\x01@\x01\x04self\xc7\0\0\xcd\
+\0\x04\0\x19[method]context.get-sorts\x01N\x01k9\x01@\x01\x04self\xc7\0\0\xcf\0\x04\
+\0\x19[method]context.get-limit\x01P\x03\0\x1dsupabase:wrappers/types@0.2.0\x05\x03\
+\x02\x03\0\x03\x04cell\x01B\x0d\x02\x03\x02\x01\x04\x04\0\x04cell\x03\0\0\x01@\x01\
+\x03msgs\x01\0\x04\0\x0breport-info\x01\x02\x04\0\x0dreport-notice\x01\x02\x04\0\
+\x0ereport-warning\x01\x02\x04\0\x0creport-error\x01\x02\x01k\x01\x01@\x01\x04ce\
+ll\x03\0s\x04\0\x0ecell-to-string\x01\x04\x01ks\x01@\x01\x09secret-ids\0\x05\x04\
+\0\x10get-vault-secret\x01\x06\x03\0\x1dsupabase:wrappers/utils@0.2.0\x05\x05\x02\
+\x03\0\x03\x03row\x02\x03\0\x03\x1aimport-foreign-schema-stmt\x02\x03\0\x03\x07c\
+ontext\x02\x03\0\x03\x09fdw-error\x02\x03\0\x03\x0afdw-result\x01B%\x02\x03\x02\x01\
+\x04\x04\0\x04cell\x03\0\0\x02\x03\x02\x01\x06\x04\0\x03row\x03\0\x02\x02\x03\x02\
+\x01\x07\x04\0\x1aimport-foreign-schema-stmt\x03\0\x04\x02\x03\x02\x01\x08\x04\0\
+\x07context\x03\0\x06\x02\x03\x02\x01\x09\x04\0\x09fdw-error\x03\0\x08\x02\x03\x02\
+\x01\x0a\x04\0\x0afdw-result\x03\0\x0a\x01@\0\0s\x04\0\x18host-version-requireme\
+nt\x01\x0c\x01h\x07\x01@\x01\x03ctx\x0d\0\x0b\x04\0\x04init\x01\x0e\x04\0\x0abeg\
+in-scan\x01\x0e\x01h\x03\x01ky\x01j\x01\x10\x01\x09\x01@\x02\x03ctx\x0d\x03row\x0f\
+\0\x11\x04\0\x09iter-scan\x01\x12\x04\0\x07re-scan\x01\x0e\x04\0\x08end-scan\x01\
+\x0e\x04\0\x0cbegin-modify\x01\x0e\x01@\x02\x03ctx\x0d\x03row\x0f\0\x0b\x04\0\x06\
+insert\x01\x13\x01@\x03\x03ctx\x0d\x05rowid\x01\x07new-row\x0f\0\x0b\x04\0\x06up\
+date\x01\x14\x01@\x02\x03ctx\x0d\x05rowid\x01\0\x0b\x04\0\x06delete\x01\x15\x04\0\
+\x0aend-modify\x01\x0e\x01ps\x01j\x01\x16\x01\x09\x01@\x02\x03ctx\x0d\x04stmt\x05\
+\0\x17\x04\0\x15import-foreign-schema\x01\x18\x04\0\x20supabase:wrappers/routine\
+s@0.2.0\x05\x0b\x04\03powabase:supabase-fdw-corrently/corrently-fdw@0.2.1\x04\0\x0b\
+\x13\x01\0\x0dcorrently-fdw\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dw\
+it-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -0,0 +1,304 @@
+// Layered FDW configuration
+//
+// Every connection setting can be set on the server or overridden per foreign
+// table. Precedence: table option > server option > built-in default. The
+// settings are resolved and validated once per scan / modify into `Config`.
+
+use crate::bindings::supabase::wrappers::{
+    types::{Context, OptionsType},
+    utils,
+};
+use crate::error::{CorrentlyError, CorrentlyResult};
+use crate::log::LogLevel;
+use crate::{headers, HISTORY_MAX_HOURS};
+
+/// Default Corrently API base URL (`api_url` option)
+pub const DEFAULT_API_URL: &str = "https://api.corrently.io";
+
+/// Integer option that can be set on the server or table, with its range and default
+/// (shared by option resolution and up-front validation)
+pub struct NumericOption {
+    pub key: &'static str,
+    pub min: i64,
+    pub max: i64,
+    /// Value when unset (None: no limit)
+    pub default: Option<i64>,
+}
+
+/// Retries for rate-limited (429) and failed (5xx) requests
+pub const MAX_RETRIES: NumericOption = NumericOption {
+    key: "max_retries",
+    min: 0,
+    max: 10,
+    default: Some(2),
+};
+
+/// Delay before the first retry, doubled per attempt
+pub const RETRY_BACKOFF_MS: NumericOption = NumericOption {
+    key: "retry_backoff_ms",
+    min: 0,
+    max: 60_000,
+    default: Some(500),
+};
+
+/// Pause between consecutive API requests
+pub const REQUEST_INTERVAL_MS: NumericOption = NumericOption {
+    key: "request_interval_ms",
+    min: 0,
+    max: 60_000,
+    default: Some(0),
+};
+
+/// API request budget per scan (unlimited by default)
+pub const MAX_REQUESTS_PER_SCAN: NumericOption = NumericOption {
+    key: "max_requests_per_scan",
+    min: 1,
+    max: 2_000,
+    default: None,
+};
+
+/// Response size accepted before parsing (larger responses fail the request)
+pub const MAX_RESPONSE_BYTES: NumericOption = NumericOption {
+    key: "max_response_bytes",
+    min: 1_024,
+    max: 100_000_000,
+    default: Some(10_000_000),
+};
+
+/// Hours per request when paging through historic data
+pub const HISTORY_CHUNK_HOURS: NumericOption = NumericOption {
+    key: "history_chunk_hours",
+    min: 1,
+    max: HISTORY_MAX_HOURS,
+    default: Some(24),
+};
+
+/// Rows per INSERT request
+pub const BATCH_SIZE: NumericOption = NumericOption {
+    key: "batch_size",
+    min: 1,
+    max: 10_000,
+    default: Some(500),
+};
+
+/// Serialized payload size per INSERT request
+pub const MAX_BATCH_BYTES: NumericOption = NumericOption {
+    key: "max_batch_bytes",
+    min: 1_024,
+    max: 10_000_000,
+    default: Some(1_000_000),
+};
+
+/// All numeric options (checked by `validate`)
+pub const NUMERIC_OPTIONS: &[NumericOption] = &[
+    MAX_RETRIES,
+    RETRY_BACKOFF_MS,
+    REQUEST_INTERVAL_MS,
+    MAX_REQUESTS_PER_SCAN,
+    MAX_RESPONSE_BYTES,
+    HISTORY_CHUNK_HOURS,
+    BATCH_SIZE,
+    MAX_BATCH_BYTES,
+];
+
+impl NumericOption {
+    /// Parse a value and check it against the range
+    pub fn parse(&self, value: &str) -> Result<i64, String> {
+        match value.trim().parse::<i64>() {
+            Ok(n) if (self.min..=self.max).contains(&n) => Ok(n),
+            _ => Err(format!(
+                "invalid {} '{}' (expected an integer between {} and {})",
+                self.key, value, self.min, self.max
+            )),
+        }
+    }
+
+    /// Resolve the option (table > server > default), None if unset without a default
+    pub fn resolve<T: TryFrom<i64>>(&self, ctx: &Context) -> CorrentlyResult<Option<T>> {
+        let value = match option(ctx, self.key) {
+            Some(v) => Some(self.parse(&v).map_err(CorrentlyError::Config)?),
+            None => self.default,
+        };
+        value
+            .map(|n| {
+                T::try_from(n).map_err(|_| {
+                    CorrentlyError::Config(format!("{} {} is out of range", self.key, n))
+                })
+            })
+            .transpose()
+    }
+
+    /// Resolve an option that has a default
+    pub fn value<T: TryFrom<i64> + Default>(&self, ctx: &Context) -> CorrentlyResult<T> {
+        Ok(self.resolve(ctx)?.unwrap_or_default())
+    }
+}
+
+/// Resolved settings for one scan / modify
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub api_url: String,
+    pub api_key: String,
+    /// Where the API key came from, e.g. "vault (server)" or "plain_text (table)"
+    pub auth_mode: String,
+    pub max_retries: u32,
+    pub retry_backoff_ms: u64,
+    pub request_interval_ms: u64,
+    pub max_requests_per_scan: Option<u32>,
+    pub max_response_bytes: usize,
+    pub api_version: Option<String>,
+    pub endpoint_path: Option<String>,
+    /// Request headers (defaults, then server, then table options)
+    pub headers: Vec<(String, String)>,
+    /// Correlation id header name (None when disabled)
+    pub correlation_header: Option<String>,
+    pub validate_postal_code: bool,
+    pub log_level: LogLevel,
+}
+
+/// Check whether a boolean-like option is switched off ('off', 'false', '0', 'no')
+pub fn is_disabled(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "off" | "false" | "0" | "no"
+    )
+}
+
+/// Resolve an option from the table, falling back to the server option
+pub fn option(ctx: &Context, key: &str) -> Option<String> {
+    ctx.get_options(&OptionsType::Table)
+        .get(key)
+        .or_else(|| ctx.get_options(&OptionsType::Server).get(key))
+}
+
+impl Config {
+    /// Resolve and validate all settings (table > server > default)
+    pub fn load(ctx: &Context) -> CorrentlyResult<Self> {
+        let layers = [
+            ctx.get_options(&OptionsType::Server),
+            ctx.get_options(&OptionsType::Table),
+        ];
+
+        // API key: the most specific layer wins, Vault (recommended) before plain text (deprecated)
+        let key_option = ["server", "table"]
+            .iter()
+            .zip(&layers)
+            .rev()
+            .find_map(|(level, opts)| {
+                opts.get("api_key_id")
+                    .map(|id| (id, true, *level))
+                    .or_else(|| opts.get("api_key").map(|key| (key, false, *level)))
+            });
+        let auth_mode = match &key_option {
+            Some((_, true, level)) => format!("vault ({})", level),
+            Some((_, false, level)) => format!("plain_text ({})", level),
+            None => String::new(),
+        };
+        let api_key = match key_option {
+            Some((vault_id, true, _)) => utils::get_vault_secret(&vault_id).ok_or_else(|| {
+                CorrentlyError::Auth(
+                    "Failed to retrieve API key from Vault. Ensure the secret exists and is accessible."
+                        .to_string(),
+                )
+            })?,
+            Some((plain_key, false, _)) => {
+                utils::report_warning(
+                    "Using plain text 'api_key' is deprecated for security reasons. \
+                     Please migrate to 'api_key_id' with Vault. \
+                     See: https://supabase.com/docs/guides/database/vault",
+                );
+                plain_key
+            }
+            None => {
+                return Err(CorrentlyError::Auth(
+                    "Either 'api_key' or 'api_key_id' must be provided in server or table options"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let api_url = option(ctx, "api_url")
+            .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        let max_retries = MAX_RETRIES.value(ctx)?;
+        let retry_backoff_ms = RETRY_BACKOFF_MS.value(ctx)?;
+        let request_interval_ms = REQUEST_INTERVAL_MS.value(ctx)?;
+        let max_requests_per_scan = MAX_REQUESTS_PER_SCAN.resolve(ctx)?;
+        let max_response_bytes = MAX_RESPONSE_BYTES.value(ctx)?;
+
+        // Headers merge per name: defaults, then server, then table options
+        let mut request_headers = vec![
+            (
+                "user-agent".to_owned(),
+                headers::DEFAULT_USER_AGENT.to_string(),
+            ),
+            ("accept".to_owned(), "application/json".to_string()),
+        ];
+        for opts in &layers {
+            if let Some(user_agent) = opts.get("user_agent") {
+                headers::merge(
+                    &mut request_headers,
+                    vec![("user-agent".to_owned(), user_agent)],
+                );
+            }
+            if let Some(plain) = opts.get("headers") {
+                headers::merge(
+                    &mut request_headers,
+                    headers::parse("headers", &plain).map_err(CorrentlyError::Config)?,
+                );
+            }
+            if let Some(secret) = opts.get("secret_headers") {
+                headers::merge(&mut request_headers, Self::resolve_secret_headers(&secret)?);
+            }
+        }
+
+        let log_level = option(ctx, "log_level")
+            .map(|v| LogLevel::parse(&v).map_err(CorrentlyError::Config))
+            .transpose()?
+            .unwrap_or_default();
+
+        let correlation_header = option(ctx, "correlation_header")
+            .unwrap_or_else(|| headers::DEFAULT_CORRELATION_HEADER.to_string());
+        let correlation_header = (!is_disabled(&correlation_header))
+            .then(|| correlation_header.trim().to_ascii_lowercase());
+
+        Ok(Self {
+            api_url,
+            api_key,
+            auth_mode,
+            max_retries,
+            retry_backoff_ms,
+            request_interval_ms,
+            max_requests_per_scan,
+            max_response_bytes,
+            api_version: option(ctx, "api_version"),
+            // Table only: the path replaces the whole request path of one object
+            endpoint_path: ctx.get_options(&OptionsType::Table).get("endpoint_path"),
+            headers: request_headers,
+            correlation_header,
+            validate_postal_code: !option(ctx, "validate_postal_code")
+                .is_some_and(|v| is_disabled(&v)),
+            log_level,
+        })
+    }
+
+    /// Resolve `secret_headers` values (Vault secret IDs), like api_key_id
+    fn resolve_secret_headers(value: &str) -> CorrentlyResult<Vec<(String, String)>> {
+        headers::parse("secret_headers", value)
+            .map_err(CorrentlyError::Config)?
+            .into_iter()
+            .map(|(name, vault_id)| {
+                utils::get_vault_secret(&vault_id)
+                    .map(|secret| (name.clone(), secret))
+                    .ok_or_else(|| {
+                        CorrentlyError::Config(format!(
+                            "Failed to retrieve value of header '{}' from Vault. Ensure the secret exists and is accessible.",
+                            name
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
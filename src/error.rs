@@ -0,0 +1,226 @@
+// Error kinds with stable error codes
+//
+// Internally errors carry a kind; at the WASM boundary they are rendered into
+// the `FdwError` string as
+//   CORRENTLY-E002 auth: Failed to retrieve API key from Vault. (hint: ...)
+// so callers can pattern-match on the code instead of the message text.
+// Codes are part of the public interface: never renumber or reuse them.
+
+use std::fmt;
+
+use serde_json::Value as JsonValue;
+
+use crate::bindings::supabase::wrappers::http;
+use crate::endpoints;
+
+/// Maximum length of a raw (non-JSON) error body quoted in messages
+const MAX_BODY_PREVIEW: usize = 200;
+
+/// FDW error classified by kind (each kind has a stable code and a hint)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorrentlyError {
+    /// Invalid or inconsistent server / table options, unsupported object or column
+    Config(String),
+    /// Missing, invalid or expired API key
+    Auth(String),
+    /// Unknown postal code or endpoint (HTTP 404)
+    NotFound(String),
+    /// API quota exceeded (HTTP 429) or per-scan request budget used up
+    RateLimited(String),
+    /// API unavailable, failing (5xx), unreachable or returning oversized responses
+    Upstream(String),
+    /// Unexpected API response format
+    Parse(String),
+    /// Invalid WHERE clause values or inserted rows
+    Validation(String),
+}
+
+/// Result of internal FDW operations
+pub type CorrentlyResult<T = ()> = Result<T, CorrentlyError>;
+
+impl CorrentlyError {
+    /// Classify an HTTP error response of the Corrently API with an actionable message
+    /// `retries` is the number of retries already spent on the request (429 / 5xx)
+    pub fn from_response(resp: &http::Response, retries: u32) -> Self {
+        let status = resp.status_code;
+        let detail = match error_detail(&resp.body) {
+            Some(detail) => format!(" (API says: {})", detail),
+            None => String::new(),
+        };
+
+        match status {
+            401 | 403 => Self::Auth(format!(
+                "Corrently API returned {}: invalid or expired API key, check api_key_id Vault secret{}",
+                status, detail
+            )),
+            404 => Self::NotFound(format!(
+                "Corrently API returned 404: unknown postal code or endpoint ({}){}",
+                endpoints::redact_url(&resp.url),
+                detail
+            )),
+            429 => Self::RateLimited(format!(
+                "Corrently API quota exceeded{} after {} retries{}",
+                quota_details(&resp.headers),
+                retries,
+                detail
+            )),
+            500..=599 => Self::Upstream(format!(
+                "Corrently API upstream outage: status {} after {} retries (raise max_retries / retry_backoff_ms or retry later){}",
+                status, retries, detail
+            )),
+            400 | 422 => Self::Validation(format!(
+                "Corrently API rejected the request with status {}{}",
+                status, detail
+            )),
+            _ => Self::Upstream(format!(
+                "Corrently API returned unexpected status {}{}",
+                status, detail
+            )),
+        }
+    }
+
+    /// Stable error code (`CORRENTLY-E###`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "CORRENTLY-E001",
+            Self::Auth(_) => "CORRENTLY-E002",
+            Self::NotFound(_) => "CORRENTLY-E003",
+            Self::RateLimited(_) => "CORRENTLY-E004",
+            Self::Upstream(_) => "CORRENTLY-E005",
+            Self::Parse(_) => "CORRENTLY-E006",
+            Self::Validation(_) => "CORRENTLY-E007",
+        }
+    }
+
+    /// Short kind label shown after the code
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "config",
+            Self::Auth(_) => "auth",
+            Self::NotFound(_) => "not found",
+            Self::RateLimited(_) => "rate limited",
+            Self::Upstream(_) => "upstream",
+            Self::Parse(_) => "parse",
+            Self::Validation(_) => "validation",
+        }
+    }
+
+    /// What to check to resolve the error
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "check the server and foreign table OPTIONS",
+            Self::Auth(_) => "check the api_key_id Vault secret and the Corrently account",
+            Self::NotFound(_) => {
+                "check the postal code and the api_version / endpoint_path options"
+            }
+            Self::RateLimited(_) => "wait for the quota to reset or narrow the query",
+            Self::Upstream(_) => "the Corrently API is unavailable or slow, retry later",
+            Self::Parse(_) => "the API response format changed, check api_version / endpoint_path",
+            Self::Validation(_) => "check the WHERE clause or the inserted values",
+        }
+    }
+
+    /// Error message without code and hint
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Config(m)
+            | Self::Auth(m)
+            | Self::NotFound(m)
+            | Self::RateLimited(m)
+            | Self::Upstream(m)
+            | Self::Parse(m)
+            | Self::Validation(m) => m,
+        }
+    }
+
+    /// Prefix the message with context, keeping the kind
+    pub fn context(self, context: impl fmt::Display) -> Self {
+        let wrap = |m: String| format!("{}: {}", context, m);
+        match self {
+            Self::Config(m) => Self::Config(wrap(m)),
+            Self::Auth(m) => Self::Auth(wrap(m)),
+            Self::NotFound(m) => Self::NotFound(wrap(m)),
+            Self::RateLimited(m) => Self::RateLimited(wrap(m)),
+            Self::Upstream(m) => Self::Upstream(wrap(m)),
+            Self::Parse(m) => Self::Parse(wrap(m)),
+            Self::Validation(m) => Self::Validation(wrap(m)),
+        }
+    }
+}
+
+impl fmt::Display for CorrentlyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} (hint: {})",
+            self.code(),
+            self.kind(),
+            self.message(),
+            self.hint()
+        )
+    }
+}
+
+/// Render into the `FdwError` string returned to Postgres
+impl From<CorrentlyError> for String {
+    fn from(err: CorrentlyError) -> Self {
+        err.to_string()
+    }
+}
+
+/// Error text from a Corrently error body
+/// Accepted shapes: {"error": "..."}, {"error": {"message": "..."}}, {"message": "..."}, {"msg": "..."};
+/// other bodies are quoted verbatim (truncated)
+fn error_detail(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return None;
+    }
+
+    let json_detail = serde_json::from_str::<JsonValue>(body)
+        .ok()
+        .and_then(|json| {
+            ["error", "message", "msg", "err"]
+                .iter()
+                .find_map(|key| match json.get(*key)? {
+                    JsonValue::String(s) => Some(s.clone()),
+                    nested => nested
+                        .get("message")
+                        .and_then(|m| m.as_str())
+                        .map(str::to_owned),
+                })
+        });
+
+    Some(
+        json_detail.unwrap_or_else(|| match body.char_indices().nth(MAX_BODY_PREVIEW) {
+            Some((end, _)) => format!("{}...", &body[..end]),
+            None => body.to_string(),
+        }),
+    )
+}
+
+/// Quota details from rate limit response headers (limit, remaining, reset, Retry-After)
+fn quota_details(headers: &[(String, String)]) -> String {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.trim().to_string())
+    };
+
+    let details: Vec<String> = [
+        ("limit", header("x-ratelimit-limit")),
+        ("remaining", header("x-ratelimit-remaining")),
+        ("resets in (s)", header("x-ratelimit-reset")),
+        ("retry after (s)", header("retry-after")),
+    ]
+    .into_iter()
+    .filter_map(|(label, value)| value.map(|v| format!("{} {}", label, v)))
+    .collect();
+
+    if details.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", details.join(", "))
+    }
+}
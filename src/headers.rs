@@ -0,0 +1,51 @@
+// Custom HTTP header options
+//
+// `headers` and `secret_headers` (server or table option) hold a JSON object of
+// header names to values. For `secret_headers` the values are Vault secret IDs,
+// resolved when the options are loaded. Names are lower-cased; later layers
+// (table over server, secrets over plain values) replace earlier ones by name.
+
+/// Default user-agent (`user_agent` option)
+pub const DEFAULT_USER_AGENT: &str = "Supabase Wrappers Corrently FDW";
+
+/// Default correlation id header name (`correlation_header` option)
+pub const DEFAULT_CORRELATION_HEADER: &str = "x-correlation-id";
+
+/// Parse a JSON header option (`{"x-tenant-id": "abc"}`)
+pub fn parse(option: &str, value: &str) -> Result<Vec<(String, String)>, String> {
+    let invalid = || {
+        format!(
+            "invalid {} option: expected a JSON object of string values (e.g. '{{\"x-tenant-id\":\"abc\"}}')",
+            option
+        )
+    };
+
+    let map: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(value).map_err(|_| invalid())?;
+
+    map.into_iter()
+        .map(|(name, value)| {
+            let name = name.trim().to_ascii_lowercase();
+            if name.is_empty() || !name.bytes().all(|b| b.is_ascii_graphic() && b != b':') {
+                return Err(format!(
+                    "invalid header name '{}' in {} option",
+                    name, option
+                ));
+            }
+            match value {
+                serde_json::Value::String(v) => Ok((name, v)),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Merge headers, replacing existing entries with the same name
+pub fn merge(headers: &mut Vec<(String, String)>, overrides: Vec<(String, String)>) {
+    for (name, value) in overrides {
+        match headers.iter_mut().find(|(n, _)| *n == name) {
+            Some(existing) => existing.1 = value,
+            None => headers.push((name, value)),
+        }
+    }
+}
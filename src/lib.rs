@@ -13,34 +13,141 @@
 
 #[allow(warnings)]
 mod bindings;
+mod config;
+mod endpoints;
+mod error;
+mod headers;
+mod log;
+mod metrics;
+mod modify;
+mod plz;
+mod ratelimit;
+mod resample;
+mod validate;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 use serde_json::Value as JsonValue;
 
 use bindings::{
     exports::supabase::wrappers::routines::Guest,
     supabase::wrappers::{
-        http, stats,
+        http, stats, time,
         types::{
             Cell, Column, Context, FdwError, FdwResult, ImportForeignSchemaStmt, OptionsType, Qual,
-            Row, Value,
+            Row, TypeOid, Value,
         },
-        utils,
     },
 };
+use endpoints::{Endpoint, Route};
+use error::{CorrentlyError, CorrentlyResult};
+use log::LogLevel;
+use resample::{Aggregation, Interpolation, Plan, Resolution};
 
 static FDW_NAME: &str = "CorrentlyFdw";
 
+// Supported foreign table objects (OPTIONS (object '...'))
+static OBJECTS: &[&str] = &[
+    "gsi_prediction",
+    "co2_savings",
+    "gsi_events",
+    "gsi_dispatch",
+    "co2_footprint",
+    "gsi_history",
+    "tariff_prices",
+    "gsi_advisor",
+    "meter_readings",
+    "status",
+];
+
+// WHERE clause columns consumed by each object (all other conditions are evaluated by PostgreSQL)
+static PUSHDOWN_QUALS: &[(&str, &[&str])] = &[
+    (
+        "gsi_prediction",
+        &[
+            "postal_code",
+            "federal_state",
+            "hours",
+            "resolution",
+            "interpolation",
+            "aggregation",
+        ],
+    ),
+    (
+        "co2_savings",
+        &[
+            "postal_code",
+            "energy_kwh",
+            "flexibility_hours",
+            "duration_hours",
+        ],
+    ),
+    (
+        "gsi_events",
+        &[
+            "postal_code",
+            "green_threshold",
+            "price_threshold",
+            "event_type",
+        ],
+    ),
+    ("gsi_dispatch", &["postal_code"]),
+    (
+        "co2_footprint",
+        &["postal_code", "energy_kwh", "period_start", "period_end"],
+    ),
+    ("gsi_history", &["postal_code", "forecast_start_time"]),
+    ("tariff_prices", &["postal_code", "period_start"]),
+    ("gsi_advisor", &["postal_code", "window_start"]),
+];
+
+// Historic data paging (one API request per chunk)
+const HISTORY_MAX_HOURS: i64 = 31 * 24;
+const MS_PER_HOUR: i64 = 3_600_000;
+
+// Write-capable objects: (object, API endpoint, array key of the JSON request body,
+// (column, API field) of the payload)
+type WriteObject = (
+    &'static str,
+    Endpoint,
+    &'static str,
+    &'static [(&'static str, &'static str)],
+);
+static WRITE_OBJECTS: &[WriteObject] = &[(
+    "meter_readings",
+    Endpoint::MeterReadings,
+    "readings",
+    &[
+        ("meter_id", "meterId"),
+        ("reading_time", "timeStamp"),
+        ("reading_kwh", "reading"),
+    ],
+)];
+
+// Meter readings may be at most this far in the future (clock skew)
+const METER_READING_MAX_FUTURE_MS: i64 = 5 * 60 * 1000;
+
 /// Main FDW struct holding all state for Corrently API queries
 #[derive(Debug, Default)]
 struct CorrentlyFdw {
-    // Server options (from CREATE SERVER)
-    base_url: String,
-    api_key: String,
-    headers: Vec<(String, String)>,
+    // Connection settings (table options > server options > defaults, resolved per scan)
+    config: config::Config,
+    rate_limiter: ratelimit::RateLimiter,
+    metrics: metrics::Metrics, // written to the stats metadata in end_scan / end_modify
+    correlation_id: String,
+
+    // Table options (from CREATE FOREIGN TABLE)
+    object: String,
 
     // Query parameters (from WHERE clause)
     postal_code: String,
+    postal_codes: Vec<String>,
+    site_labels: Vec<(String, String)>, // (postal code, label) from the site_labels table option
     hours: Option<i64>,
+    resolution: Option<Resolution>,
+    interpolation: Interpolation,
+    aggregation: Aggregation,
 
     // Cached forecast data (flattened from API response array)
     // Each Vec contains N elements (one per forecast hour, typically ~113)
@@ -61,8 +168,17 @@ struct CorrentlyFdw {
     postal_code_values: Vec<String>,
     forecast_created_at: Vec<i64>, // Milliseconds (converted to TIMESTAMP WITH TIME ZONE)
 
+    // Derived rows for objects computed from the forecast (one JSON object per row)
+    object_rows: Vec<JsonValue>,
+
     // Iteration state
     current_row: usize,
+    rows_out: usize,   // rows returned by the current scan (across re-scans)
+    scan_started: i64, // epoch seconds
+
+    // Modify state (rows buffered by insert, submitted in batches)
+    modify: modify::ModifyBuffer,
+    rowid_field: String, // API field of the rowid_column, keys rows rejected by the API
 }
 
 // Static instance pattern (required for Supabase WASM FDW)
@@ -87,6 +203,14 @@ impl CorrentlyFdw {
         self.forecast_start_time.len()
     }
 
+    /// Get total number of rows produced by the current scan
+    fn scan_row_count(&self) -> usize {
+        match self.object.as_str() {
+            "gsi_prediction" | "gsi_history" => self.row_count(),
+            _ => self.object_rows.len(),
+        }
+    }
+
     /// Clear all cached forecast data
     fn clear_data(&mut self) {
         self.forecast_start_time.clear();
@@ -105,9 +229,108 @@ impl CorrentlyFdw {
         self.green_mix_co2_g_kwh.clear();
         self.postal_code_values.clear();
         self.forecast_created_at.clear();
+        self.postal_codes.clear();
+        self.site_labels.clear();
+        self.resolution = None;
+        self.interpolation = Interpolation::default();
+        self.aggregation = Aggregation::default();
+        self.object_rows.clear();
         self.current_row = 0;
     }
 
+    /// Parse the postal_codes / site_labels table options into (postal code, label) pairs
+    /// Sites without a label are labelled with their postal code
+    fn parse_site_list(
+        postal_codes: &str,
+        site_labels: Option<String>,
+    ) -> Result<Vec<(String, String)>, String> {
+        let codes: Vec<String> = postal_codes
+            .split(',')
+            .map(|pc| pc.trim().to_string())
+            .filter(|pc| !pc.is_empty())
+            .collect();
+        if codes.is_empty() {
+            return Err("postal_codes table option must list at least one postal code".to_string());
+        }
+
+        let labels: Vec<String> = match site_labels {
+            Some(labels) => labels.split(',').map(|l| l.trim().to_string()).collect(),
+            None => codes.clone(),
+        };
+        if labels.len() != codes.len() {
+            return Err(format!(
+                "site_labels has {} entries but postal_codes has {} (one label per postal code)",
+                labels.len(),
+                codes.len()
+            ));
+        }
+
+        let mut sites: Vec<(String, String)> = Vec::new();
+        for (code, label) in codes.into_iter().zip(labels) {
+            if sites.iter().any(|(c, _)| *c == code) {
+                return Err(format!("duplicate postal code '{}' in postal_codes", code));
+            }
+            sites.push((code, label));
+        }
+        Ok(sites)
+    }
+
+    /// Label of the site a postal code belongs to (the postal code itself if unlabelled)
+    fn site_label(&self, postal_code: &str) -> String {
+        self.site_labels
+            .iter()
+            .find(|(pc, _)| pc == postal_code)
+            .map_or_else(|| postal_code.to_string(), |(_, label)| label.clone())
+    }
+
+    /// Resolve a scan setting from the WHERE clause, falling back to the table option
+    fn qual_or_table_option(ctx: &Context, quals: &[Qual], key: &str) -> Option<String> {
+        Self::extract_qual_string(quals, key)
+            .or_else(|| ctx.get_options(&OptionsType::Table).get(key))
+    }
+
+    /// Resolve an option from the table, falling back to the server option
+    fn table_or_server_option(ctx: &Context, key: &str) -> Option<String> {
+        config::option(ctx, key)
+    }
+
+    /// Resample the parsed hourly forecast to the requested resolution
+    /// Pattern: build a bucket plan once, then apply it to every column vector
+    fn resample_forecast(&mut self, resolution: &Resolution) {
+        let Some(plan) = Plan::build(
+            &self.forecast_period_start,
+            &self.forecast_period_end,
+            resolution,
+            self.interpolation,
+            self.aggregation,
+        ) else {
+            return;
+        };
+
+        self.forecast_start_time = plan.period_start.clone();
+        self.forecast_period_start = plan.period_start.clone();
+        self.forecast_period_end = plan.period_end.clone();
+        self.green_energy_index = plan.apply_f64(&self.green_energy_index);
+        self.renewable_energy_pct = plan.apply_i64(&self.renewable_energy_pct);
+        self.wind_energy_pct = plan.apply_i64(&self.wind_energy_pct);
+        self.solar_energy_pct = plan.apply_i64(&self.solar_energy_pct);
+        self.net_wind_energy_pct = plan.apply_i64(&self.net_wind_energy_pct);
+        self.net_solar_energy_pct = plan.apply_i64(&self.net_solar_energy_pct);
+        self.smart_city_index = plan.apply_i64(&self.smart_city_index);
+        self.energy_price_eur_kwh = plan.apply_f64(&self.energy_price_eur_kwh);
+        self.co2_baseline_g_kwh = plan.apply_f64(&self.co2_baseline_g_kwh);
+        self.standard_mix_co2_g_kwh = plan.apply_i64(&self.standard_mix_co2_g_kwh);
+        self.green_mix_co2_g_kwh = plan.apply_i64(&self.green_mix_co2_g_kwh);
+        self.postal_code_values = plan.pick(&self.postal_code_values, false);
+        self.forecast_created_at = plan.pick(&self.forecast_created_at, true);
+
+        self.config.log_level.info(&format!(
+            "Resampled forecast to '{}': {} rows",
+            resolution.label,
+            self.row_count()
+        ));
+    }
+
     /// Extract string value from quals (WHERE clause)
     fn extract_qual_string(quals: &[Qual], field: &str) -> Option<String> {
         quals
@@ -131,19 +354,1185 @@ impl CorrentlyFdw {
             })
     }
 
+    /// Extract f64 value from quals (WHERE clause)
+    fn extract_qual_f64(quals: &[Qual], field: &str) -> Option<f64> {
+        quals
+            .iter()
+            .find(|q| q.field() == field && q.operator() == "=")
+            .and_then(|q| match q.value() {
+                Value::Cell(Cell::Numeric(n)) => Some(n),
+                Value::Cell(Cell::F64(f)) => Some(f),
+                Value::Cell(Cell::F32(f)) => Some(f as f64),
+                Value::Cell(Cell::I64(i)) => Some(i as f64),
+                Value::Cell(Cell::I32(i)) => Some(i as f64),
+                _ => None,
+            })
+    }
+
+    /// Resolve the request path of an endpoint (api_version / endpoint_path table options)
+    fn route(&self, endpoint: Endpoint) -> CorrentlyResult<Route> {
+        Route::resolve(
+            endpoint,
+            self.config.api_version.as_deref(),
+            self.config.endpoint_path.as_deref(),
+        )
+        .map_err(CorrentlyError::Config)
+    }
+
+    /// Resolve the configuration for the current scan or modify
+    /// Pattern: built once per scan (table > server > default), plus a fresh correlation id
+    fn load_config(&mut self, ctx: &Context) -> CorrentlyResult {
+        self.config = config::Config::load(ctx)?;
+        self.rate_limiter = ratelimit::RateLimiter::new(
+            self.config.request_interval_ms,
+            self.config.max_requests_per_scan,
+        );
+
+        // Correlation id (one per scan / modify), logged so requests can be traced end-to-end.
+        // Derived locally (no stats metadata write) from the clock and a hash of the statement:
+        // object, WHERE clause, site options and the instance address. Identical statements
+        // started in the same second by different sessions may share an id.
+        if let Some(header) = self.config.correlation_header.clone() {
+            let now = time::epoch_secs();
+            let table_opts = ctx.get_options(&OptionsType::Table);
+            let mut hasher = DefaultHasher::new();
+            (
+                now,
+                &self.object,
+                ctx.get_quals()
+                    .iter()
+                    .map(|q| q.deparse())
+                    .collect::<Vec<_>>(),
+                table_opts.get("postal_code"),
+                table_opts.get("postal_codes"),
+                self as *const Self as usize,
+            )
+                .hash(&mut hasher);
+            self.correlation_id = format!("corrently-fdw-{}-{:08x}", now, hasher.finish() as u32);
+            headers::merge(
+                &mut self.config.headers,
+                vec![(header, self.correlation_id.clone())],
+            );
+            self.config.log_level.info(&format!(
+                "Corrently request correlation id: {}",
+                self.correlation_id
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Send a request, retrying rate-limited (429) and failed (5xx) GET responses
+    /// Returns the final response and the number of retries spent on it
+    ///
+    /// Pattern: exponential backoff from retry_backoff_ms, honoring a Retry-After header (seconds)
+    /// up to the longest backoff delay. Every attempt takes a slot from the rate limiter, so
+    /// max_requests_per_scan also bounds retries. POST requests are never retried: the API may
+    /// have accepted the body before failing, and a resubmitted batch would duplicate rows.
+    /// `retry` false sends a single attempt (status ping).
+    fn send_request(
+        &mut self,
+        req: &http::Request,
+        retry: bool,
+    ) -> CorrentlyResult<(http::Response, u32)> {
+        let retryable_method = retry && matches!(req.method, http::Method::Get);
+        let max_delay_ms = self
+            .config
+            .retry_backoff_ms
+            .saturating_mul(1u64 << self.config.max_retries.min(32));
+        let mut attempt = 0;
+        loop {
+            self.rate_limiter.acquire()?;
+            let resp = self.send_once(req)?;
+
+            let retryable = resp.status_code == 429 || resp.status_code >= 500;
+            if !retryable || !retryable_method || attempt >= self.config.max_retries {
+                return Ok((resp, attempt));
+            }
+
+            let retry_after_ms = resp
+                .headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+                .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+                .map(|secs| secs.saturating_mul(1000).min(max_delay_ms));
+            let delay_ms =
+                retry_after_ms.unwrap_or(self.config.retry_backoff_ms.saturating_mul(1 << attempt));
+
+            attempt += 1;
+            self.config.log_level.info(&format!(
+                "Corrently API returned status {}, retry {} of {} in {} ms",
+                resp.status_code, attempt, self.config.max_retries, delay_ms
+            ));
+            time::sleep(delay_ms);
+        }
+    }
+
+    /// Send a request (with retries), logging it at debug level and counting its latency
+    /// The response status is not checked
+    fn send_logged(
+        &mut self,
+        req: &http::Request,
+        retry: bool,
+    ) -> CorrentlyResult<(http::Response, u32)> {
+        let log_level = self.config.log_level;
+        if log_level.is_debug() {
+            let header_names: Vec<&str> = req.headers.iter().map(|(n, _)| n.as_str()).collect();
+            log_level.debug(&format!(
+                "request: {} {} (headers: {}{})",
+                match req.method {
+                    http::Method::Post => "POST",
+                    _ => "GET",
+                },
+                endpoints::redact_url(&req.url),
+                header_names.join(", "),
+                if req.body.is_empty() {
+                    String::new()
+                } else {
+                    format!(", body: {} bytes", req.body.len())
+                }
+            ));
+        }
+
+        let started = time::epoch_secs();
+        let result = self.send_request(req, retry);
+        let elapsed_secs = (time::epoch_secs() - started).max(0);
+        self.metrics.record_latency(elapsed_secs);
+
+        match &result {
+            Ok((resp, _)) if log_level.is_debug() => log_level.debug(&format!(
+                "response: status {}, {} bytes in {} s: {}",
+                resp.status_code,
+                resp.body.len(),
+                elapsed_secs,
+                log::body_preview(&resp.body)
+            )),
+            Ok(_) => {}
+            Err(_) => log_level.debug(&format!("request failed after {} s", elapsed_secs)),
+        }
+        result
+    }
+
+    /// Send a request (with retries), check the status and size and record the outcome in
+    /// the metrics
+    fn send_recorded(&mut self, req: &http::Request) -> CorrentlyResult<http::Response> {
+        let result = self
+            .send_logged(req, true)
+            .and_then(|(resp, retries)| Self::check_status(&resp, retries).map(|_| resp))
+            .and_then(|resp| self.check_size(&resp).map(|_| resp));
+
+        match &result {
+            Ok(_) => self.metrics.record_success(&self.object),
+            Err(err) => {
+                // The statement fails and end_scan / end_modify will not run: write now
+                self.metrics.record_failure(&self.object, &err.to_string());
+                self.metrics.flush();
+            }
+        }
+        result
+    }
+
+    /// Log which WHERE clause conditions are pushed down to the API and which PostgreSQL
+    /// evaluates locally (debug log level)
+    fn log_quals(&self, quals: &[Qual]) {
+        if !self.config.log_level.is_debug() {
+            return;
+        }
+        let pushed_fields = PUSHDOWN_QUALS
+            .iter()
+            .find(|(object, _)| *object == self.object)
+            .map_or(&[][..], |(_, fields)| *fields);
+        let (pushed, local): (Vec<&Qual>, Vec<&Qual>) = quals
+            .iter()
+            .partition(|q| pushed_fields.contains(&q.field().as_str()));
+        let deparse = |quals: Vec<&Qual>| {
+            if quals.is_empty() {
+                return "none".to_string();
+            }
+            quals
+                .iter()
+                .map(|q| q.deparse())
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        };
+        self.config.log_level.debug(&format!(
+            "quals pushed down: {}; evaluated locally: {}",
+            deparse(pushed),
+            deparse(local)
+        ));
+    }
+
+    /// Turn an HTTP error status into an actionable error (auth, not found, quota, outage)
+    fn check_status(resp: &http::Response, retries: u32) -> CorrentlyResult {
+        if resp.status_code < 400 {
+            return Ok(());
+        }
+        Err(CorrentlyError::from_response(resp, retries))
+    }
+
+    /// Reject a response larger than max_response_bytes before it is parsed
+    /// The host has already received the body in full (no streaming), but a runaway response
+    /// from a misbehaving proxy is not handed to the JSON parser
+    fn check_size(&mut self, resp: &http::Response) -> CorrentlyResult {
+        if resp.body.len() <= self.config.max_response_bytes {
+            return Ok(());
+        }
+        self.metrics.increment("aborted_requests", "oversized");
+        Err(CorrentlyError::Upstream(format!(
+            "Corrently API response of {} bytes exceeds max_response_bytes ({}), not parsed (raise max_response_bytes or narrow the query)",
+            resp.body.len(),
+            self.config.max_response_bytes
+        )))
+    }
+
+    /// Send one request through the host HTTP client
+    ///
+    /// The Wrappers HTTP interface has no per-request timeout and no streaming, so
+    /// the FDW cannot bound a request: the host client's own timeout applies and
+    /// responses are always received in full (their size is checked before parsing).
+    /// Host timeouts are counted in the stats metadata.
+    fn send_once(&mut self, req: &http::Request) -> CorrentlyResult<http::Response> {
+        match req.method {
+            http::Method::Post => http::post(req),
+            _ => http::get(req),
+        }
+        .map_err(|err| {
+            // The host returns the reqwest error as text only, e.g. "error sending request
+            // for url (...): operation timed out"; "timeout" also covers proxy / hyper wording
+            let lower = err.to_ascii_lowercase();
+            if lower.contains("timed out") || lower.contains("timeout") {
+                self.metrics.increment("aborted_requests", "timeout");
+                CorrentlyError::Upstream(format!(
+                    "Corrently API request timed out in the host HTTP client (check api_url / proxy): {}",
+                    err
+                ))
+            } else {
+                CorrentlyError::Upstream(err)
+            }
+        })
+    }
+
+    /// Build the request URL of an endpoint (accepted query parameters first, token last)
+    fn endpoint_url(
+        &self,
+        endpoint: Endpoint,
+        params: &[(&str, String)],
+    ) -> CorrentlyResult<String> {
+        let route = self.route(endpoint)?;
+        let mut url = format!("{}/{}?", self.config.api_url, route.path);
+        for (key, value) in route.filter_params(params) {
+            url.push_str(&format!("{}={}&", key, value));
+        }
+        url.push_str(&format!("token={}", self.config.api_key));
+        Ok(url)
+    }
+
+    /// Send a GET request to a Corrently API endpoint and return the response body
+    /// Pattern: shared by all objects (token auth, HTTP error handling, BytesIn stats)
+    fn fetch_endpoint(
+        &mut self,
+        endpoint: Endpoint,
+        params: &[(&str, String)],
+    ) -> CorrentlyResult<String> {
+        let url = self.endpoint_url(endpoint, params)?;
+
+        // Make HTTP request
+        let req = http::Request {
+            method: http::Method::Get,
+            url,
+            headers: self.config.headers.clone(),
+            body: String::default(),
+        };
+
+        let resp = self.send_recorded(&req)?;
+
+        self.config.log_level.info(&format!(
+            "Corrently API response: {} bytes, status {}",
+            resp.body.len(),
+            resp.status_code
+        ));
+
+        // Track stats
+        stats::inc_stats(FDW_NAME, stats::Metric::BytesIn, resp.body.len() as i64);
+
+        Ok(resp.body)
+    }
+
+    /// Send a POST request with a JSON body to a Corrently API endpoint and return the response body
+    fn post_endpoint(&mut self, endpoint: Endpoint, body: String) -> CorrentlyResult<String> {
+        let url = self.endpoint_url(endpoint, &[])?;
+
+        let mut headers = self.config.headers.clone();
+        headers.push(("content-type".to_owned(), "application/json".to_string()));
+
+        // Counted once per batch (POST requests are never retried)
+        stats::inc_stats(FDW_NAME, stats::Metric::BytesOut, body.len() as i64);
+        let req = http::Request {
+            method: http::Method::Post,
+            url,
+            headers,
+            body,
+        };
+
+        let resp = self.send_recorded(&req)?;
+
+        self.config.log_level.info(&format!(
+            "Corrently API response: {} bytes, status {}",
+            resp.body.len(),
+            resp.status_code
+        ));
+
+        Ok(resp.body)
+    }
+
+    /// Extract timestamp value (milliseconds) from quals for any of the given operators
+    fn extract_qual_timestamp(quals: &[Qual], field: &str, operators: &[&str]) -> Option<i64> {
+        quals
+            .iter()
+            .find(|q| q.field() == field && operators.contains(&q.operator().as_str()))
+            .and_then(|q| match q.value() {
+                Value::Cell(Cell::Timestamptz(us)) | Value::Cell(Cell::Timestamp(us)) => {
+                    Some(us / 1000)
+                }
+                Value::Cell(Cell::Date(secs)) => Some(secs * 1000),
+                _ => None,
+            })
+    }
+
+    /// Fetch and parse the forecast for the current postal code
+    fn fetch_forecast(&mut self) -> CorrentlyResult {
+        let mut params = vec![("zip", self.postal_code.clone())];
+        if let Some(hours_val) = self.hours {
+            params.push(("hours", hours_val.to_string()));
+        }
+
+        self.config.log_level.info(&format!(
+            "Fetching Corrently forecast for postal code: {}, hours: {:?}",
+            self.postal_code, self.hours
+        ));
+
+        let body = self.fetch_endpoint(Endpoint::Prediction, &params)?;
+
+        // Parse JSON response (appends to the flattened vectors)
+        let rows_before = self.row_count();
+        self.parse_forecast_response(&body, "forecast")?;
+        stats::inc_stats(
+            FDW_NAME,
+            stats::Metric::RowsIn,
+            (self.row_count() - rows_before) as i64,
+        );
+
+        Ok(())
+    }
+
+    /// Begin scan for the gsi_prediction object (hourly forecast, optionally resampled)
+    fn begin_gsi_prediction(&mut self, ctx: &Context, quals: &[Qual]) -> CorrentlyResult {
+        // Extract hours (optional, WHERE clause or table option)
+        self.hours = match Self::extract_qual_i64(quals, "hours") {
+            Some(hours) => Some(hours),
+            None => ctx
+                .get_options(&OptionsType::Table)
+                .get("hours")
+                .map(|v| {
+                    v.trim().parse::<i64>().map_err(|_| {
+                        CorrentlyError::Config(format!("invalid hours '{}' (expected 1-113)", v))
+                    })
+                })
+                .transpose()?,
+        };
+
+        // Extract resampling settings (optional, WHERE clause overrides table options)
+        let resolution = Self::qual_or_table_option(ctx, quals, "resolution")
+            .map(|r| Resolution::parse(&r))
+            .transpose()
+            .map_err(CorrentlyError::Validation)?;
+        self.interpolation = Self::qual_or_table_option(ctx, quals, "interpolation")
+            .map(|i| Interpolation::parse(&i))
+            .transpose()
+            .map_err(CorrentlyError::Validation)?
+            .unwrap_or_default();
+        self.aggregation = Self::qual_or_table_option(ctx, quals, "aggregation")
+            .map(|a| Aggregation::parse(&a))
+            .transpose()
+            .map_err(CorrentlyError::Validation)?
+            .unwrap_or_default();
+
+        // Fetch every requested postal code (federal_state expansion, postal_codes table option)
+        // Pattern: one request per site, sharing the rate limiter
+        for postal_code in self.postal_codes.clone() {
+            self.postal_code = postal_code;
+            self.fetch_forecast()?;
+        }
+
+        // Resample hourly forecast if a different resolution was requested
+        if let Some(resolution) = resolution {
+            self.resample_forecast(&resolution);
+            self.resolution = Some(resolution);
+        }
+
+        Ok(())
+    }
+
+    /// Begin scan for the status object (health check, one row)
+    /// Pattern: resolved connection settings plus one ping request
+    ///
+    /// The FDW instance is recreated for every statement, so request counters of earlier
+    /// queries are not visible here; only the ping is live.
+    fn begin_status(&mut self, ctx: &Context) -> CorrentlyResult {
+        let now = time::epoch_secs();
+
+        // Live ping: one-hour forecast, so a bad key or outage shows without a full fetch
+        let postal_code = Self::table_or_server_option(ctx, "postal_code")
+            .map(|pc| pc.trim().to_string())
+            .filter(|pc| !pc.is_empty())
+            .ok_or_else(|| {
+                CorrentlyError::Config(
+                    "status requires a postal_code table or server option for the ping request"
+                        .to_string(),
+                )
+            })?;
+        let started = time::epoch_secs();
+        let ping = self.ping(&postal_code);
+        let ping_latency_secs = (time::epoch_secs() - started).max(0);
+        let (ping_status, ping_error) = match &ping {
+            Ok(status) => (Some(*status), None),
+            Err((status, err)) => (*status, Some(err.to_string())),
+        };
+        match &ping_error {
+            None => self.metrics.record_success(&self.object),
+            Some(err) => self.metrics.record_failure(&self.object, err),
+        }
+
+        let api_version = self
+            .config
+            .api_version
+            .as_deref()
+            .unwrap_or(endpoints::DEFAULT_API_VERSION);
+        self.object_rows.push(serde_json::json!({
+            "fdw_version": env!("CARGO_PKG_VERSION"),
+            "base_url": endpoints::redact_url(&self.config.api_url),
+            "api_version": api_version,
+            "auth_mode": self.config.auth_mode,
+            "request_interval_ms": self.rate_limiter.interval_ms,
+            "max_requests_per_scan": self.rate_limiter.max_requests,
+            "ping_postal_code": postal_code,
+            "ping_ok": ping.is_ok(),
+            "ping_status": ping_status,
+            "ping_latency_secs": ping_latency_secs,
+            "ping_error": ping_error,
+            "checked_at": now * 1000,
+        }));
+
+        self.config.log_level.info(&format!(
+            "Corrently status: ping {} in {} s",
+            if ping.is_ok() { "ok" } else { "failed" },
+            ping_latency_secs
+        ));
+        Ok(())
+    }
+
+    /// Send the status ping request, returning the HTTP status (and the error on failure)
+    /// Pattern: same URL building and logging as fetch_endpoint, but a single attempt
+    fn ping(&mut self, postal_code: &str) -> Result<u16, (Option<u16>, CorrentlyError)> {
+        let params = [("zip", postal_code.to_string()), ("hours", "1".to_string())];
+        let req = http::Request {
+            method: http::Method::Get,
+            url: self
+                .endpoint_url(Endpoint::Prediction, &params)
+                .map_err(|err| (None, err))?,
+            headers: self.config.headers.clone(),
+            body: String::default(),
+        };
+
+        let (resp, _) = self.send_logged(&req, false).map_err(|err| (None, err))?;
+        stats::inc_stats(FDW_NAME, stats::Metric::BytesIn, resp.body.len() as i64);
+        Self::check_status(&resp, 0)
+            .and_then(|_| self.check_size(&resp))
+            .map_err(|err| (Some(resp.status_code), err))?;
+        Ok(resp.status_code)
+    }
+
+    /// Begin scan for the gsi_history object (past hours, same columns as gsi_prediction)
+    /// Pattern: page through the requested range in fixed-size chunks (one request each)
+    fn begin_gsi_history(&mut self, ctx: &Context, quals: &[Qual]) -> CorrentlyResult {
+        let now = time::epoch_secs() * 1000;
+
+        // Time range: forecast_start_time >= .. AND forecast_start_time < .. (defaults: last 24 hours)
+        // Kept as [from, to) in milliseconds: `<=` includes its bound, `>` excludes it
+        let field = "forecast_start_time";
+        let to = Self::extract_qual_timestamp(quals, field, &["<="])
+            .map(|ms| ms + 1)
+            .or_else(|| Self::extract_qual_timestamp(quals, field, &["<"]))
+            .unwrap_or(now)
+            .min(now);
+        let from = Self::extract_qual_timestamp(quals, field, &[">="])
+            .or_else(|| Self::extract_qual_timestamp(quals, field, &[">"]).map(|ms| ms + 1))
+            .unwrap_or(to - 24 * MS_PER_HOUR);
+
+        if from >= to {
+            return Err(CorrentlyError::Validation(
+                "invalid forecast_start_time range: start must be before end (and in the past)"
+                    .to_string(),
+            ));
+        }
+        if to - from > HISTORY_MAX_HOURS * MS_PER_HOUR {
+            return Err(CorrentlyError::Validation(format!(
+                "forecast_start_time range too large: at most {} days of history per query",
+                HISTORY_MAX_HOURS / 24
+            )));
+        }
+
+        let chunk_hours: i64 = config::HISTORY_CHUNK_HOURS.value(ctx)?;
+
+        self.config.log_level.info(&format!(
+            "Fetching Corrently history for postal code: {}, {} hours in {}-hour chunks",
+            self.postal_code,
+            (to - from) / MS_PER_HOUR,
+            chunk_hours
+        ));
+
+        // The API range is inclusive on both ends, so each chunk is sent as [start, end - 1]:
+        // it ends 1 ms before the next begins, and the last one at the `<=` bound itself
+        let mut chunk_start = from;
+        while chunk_start < to {
+            let chunk_end = (chunk_start + chunk_hours * MS_PER_HOUR).min(to);
+            let params = [
+                ("zip", self.postal_code.clone()),
+                ("from", chunk_start.to_string()),
+                ("to", (chunk_end - 1).to_string()),
+            ];
+
+            let body = self.fetch_endpoint(Endpoint::Historic, &params)?;
+            let rows_before = self.row_count();
+            self.parse_forecast_response(&body, "history")?;
+            stats::inc_stats(
+                FDW_NAME,
+                stats::Metric::RowsIn,
+                (self.row_count() - rows_before) as i64,
+            );
+
+            chunk_start = chunk_end;
+        }
+
+        Ok(())
+    }
+
+    /// Begin scan for the co2_savings object
+    /// Pattern: compare running a load immediately vs. in the lowest-CO2 hours of the window
+    fn begin_co2_savings(&mut self, quals: &[Qual]) -> CorrentlyResult {
+        let energy_kwh = Self::extract_qual_f64(quals, "energy_kwh").ok_or_else(|| {
+            CorrentlyError::Validation(
+                "energy_kwh parameter is required in WHERE clause (e.g., WHERE energy_kwh = 10)"
+                    .to_string(),
+            )
+        })?;
+        if energy_kwh <= 0.0 {
+            return Err(CorrentlyError::Validation(format!(
+                "energy_kwh must be positive, got {}",
+                energy_kwh
+            )));
+        }
+
+        let requested_flexibility = Self::extract_qual_i64(quals, "flexibility_hours");
+        let flexibility_hours = requested_flexibility.unwrap_or(24);
+        let duration_hours = Self::extract_qual_i64(quals, "duration_hours").unwrap_or(1);
+        if duration_hours < 1 || flexibility_hours < duration_hours {
+            return Err(CorrentlyError::Validation(format!(
+                "invalid window: duration_hours ({}) must be >= 1 and <= flexibility_hours ({})",
+                duration_hours, flexibility_hours
+            )));
+        }
+
+        self.fetch_forecast()?;
+
+        // The default window is clamped to the forecast; an explicit one must fit, since the
+        // echoed (effective) value has to match the WHERE clause for PostgreSQL's re-check
+        let available = self.row_count();
+        if requested_flexibility.is_some() && flexibility_hours as usize > available {
+            return Err(CorrentlyError::Validation(format!(
+                "flexibility_hours ({}) exceeds the forecast, which covers {} hours",
+                flexibility_hours, available
+            )));
+        }
+        let window = (flexibility_hours as usize).min(available);
+        let duration = duration_hours as usize;
+        if window < duration {
+            return Err(CorrentlyError::Validation(format!(
+                "forecast only covers {} hours, need at least duration_hours ({})",
+                window, duration
+            )));
+        }
+        let kwh_per_hour = energy_kwh / duration as f64;
+
+        // Baseline: run immediately (first `duration` hours) at the standard mix
+        let baseline_co2_g: f64 = self.standard_mix_co2_g_kwh[..duration]
+            .iter()
+            .map(|&g| g as f64 * kwh_per_hour)
+            .sum();
+
+        // Optimal shift: the contiguous `duration`-hour span with the lowest CO2 within the
+        // flexibility window (a running load cannot be paused), earliest start on ties
+        let span_co2 = |start: usize| -> i64 {
+            self.standard_mix_co2_g_kwh[start..start + duration]
+                .iter()
+                .sum()
+        };
+        let optimal_start = (0..=window - duration)
+            .min_by_key(|&start| (span_co2(start), start))
+            .unwrap_or(0);
+        let chosen: Vec<usize> = (optimal_start..optimal_start + duration).collect();
+
+        let optimal_co2_g: f64 = chosen
+            .iter()
+            .map(|&i| self.standard_mix_co2_g_kwh[i] as f64 * kwh_per_hour)
+            .sum();
+        let green_mix_co2_g: f64 = chosen
+            .iter()
+            .map(|&i| self.green_mix_co2_g_kwh[i] as f64 * kwh_per_hour)
+            .sum();
+        let average_co2_g =
+            self.co2_baseline_g_kwh[..window].iter().sum::<f64>() / window as f64 * energy_kwh;
+
+        let savings_co2_g = baseline_co2_g - optimal_co2_g;
+        let savings_pct = if baseline_co2_g > 0.0 {
+            savings_co2_g / baseline_co2_g * 100.0
+        } else {
+            0.0
+        };
+
+        let chosen_hours: Vec<JsonValue> = chosen
+            .iter()
+            .map(|&i| {
+                serde_json::json!({
+                    "start": self.forecast_period_start[i],
+                    "end": self.forecast_period_end[i],
+                    "energy_kwh": kwh_per_hour,
+                    "standard_mix_co2_g_kwh": self.standard_mix_co2_g_kwh[i],
+                    "green_mix_co2_g_kwh": self.green_mix_co2_g_kwh[i],
+                })
+            })
+            .collect();
+
+        self.object_rows.push(serde_json::json!({
+            "postal_code": self.postal_code,
+            "energy_kwh": energy_kwh,
+            "flexibility_hours": window,
+            "duration_hours": duration_hours,
+            "baseline_co2_g": baseline_co2_g,
+            "optimal_co2_g": optimal_co2_g,
+            "savings_co2_g": savings_co2_g,
+            "savings_pct": savings_pct,
+            "green_mix_co2_g": green_mix_co2_g,
+            "average_co2_g": average_co2_g,
+            "optimal_start_time": self.forecast_period_start[chosen[0]],
+            "baseline_start_time": self.forecast_period_start[0],
+            "chosen_hours": chosen_hours,
+        }));
+
+        self.config.log_level.info(&format!(
+            "CO2 savings for {} kWh in {}: {:.0} g baseline, {:.0} g optimal",
+            energy_kwh, self.postal_code, baseline_co2_g, optimal_co2_g
+        ));
+
+        Ok(())
+    }
+
+    /// Begin scan for the gsi_events object
+    /// Pattern: gap-and-island over forecast hours (consecutive matching hours → one event row)
+    fn begin_gsi_events(&mut self, quals: &[Qual]) -> CorrentlyResult {
+        let green_threshold = Self::extract_qual_f64(quals, "green_threshold").unwrap_or(50.0);
+        let price_threshold = Self::extract_qual_f64(quals, "price_threshold").unwrap_or(0.0);
+        let event_type = Self::extract_qual_string(quals, "event_type");
+
+        if let Some(event_type) = &event_type {
+            if event_type != "green" && event_type != "negative_price" {
+                return Err(CorrentlyError::Validation(format!(
+                    "invalid event_type '{}' (expected 'green' or 'negative_price')",
+                    event_type
+                )));
+            }
+        }
+
+        self.fetch_forecast()?;
+
+        if event_type.as_deref() != Some("negative_price") {
+            let values = self.green_energy_index.clone();
+            self.collapse_events(
+                "green",
+                green_threshold,
+                &values,
+                |v| v >= green_threshold,
+                true,
+            );
+        }
+        if event_type.as_deref() != Some("green") {
+            let values = self.energy_price_eur_kwh.clone();
+            self.collapse_events(
+                "negative_price",
+                price_threshold,
+                &values,
+                |v| v < price_threshold,
+                false,
+            );
+        }
+
+        // Echo thresholds so PostgreSQL's re-check of the WHERE clause matches
+        for row in self.object_rows.iter_mut() {
+            row["green_threshold"] = green_threshold.into();
+            row["price_threshold"] = price_threshold.into();
+        }
+
+        // Emit events chronologically across both types
+        self.object_rows.sort_by_key(|row| {
+            row.get("event_start")
+                .and_then(|v| v.as_i64())
+                .unwrap_or_default()
+        });
+
+        self.config.log_level.info(&format!(
+            "Detected {} events for postal code {}",
+            self.object_rows.len(),
+            self.postal_code
+        ));
+
+        Ok(())
+    }
+
+    /// Collapse consecutive matching forecast hours into event rows
+    /// `peak_is_max` selects the maximum (green) or minimum (price) value as the peak
+    fn collapse_events(
+        &mut self,
+        event_type: &str,
+        threshold: f64,
+        values: &[f64],
+        matches: impl Fn(f64) -> bool,
+        peak_is_max: bool,
+    ) {
+        let mut idx = 0;
+        while idx < values.len() {
+            if !matches(values[idx]) {
+                idx += 1;
+                continue;
+            }
+
+            // Extend the island while hours match and periods are contiguous
+            let start = idx;
+            let mut peak = start;
+            idx += 1;
+            while idx < values.len()
+                && matches(values[idx])
+                && self.forecast_period_start[idx] == self.forecast_period_end[idx - 1]
+            {
+                let better = if peak_is_max {
+                    values[idx] > values[peak]
+                } else {
+                    values[idx] < values[peak]
+                };
+                if better {
+                    peak = idx;
+                }
+                idx += 1;
+            }
+            let end = idx - 1;
+
+            let event_start = self.forecast_period_start[start];
+            let event_end = self.forecast_period_end[end];
+            self.object_rows.push(serde_json::json!({
+                "postal_code": self.postal_code,
+                "event_type": event_type,
+                "event_start": event_start,
+                "event_end": event_end,
+                "duration_hours": (event_end - event_start) as f64 / 3_600_000.0,
+                "peak_value": values[peak],
+                "peak_time": self.forecast_period_start[peak],
+                "threshold": threshold,
+            }));
+        }
+    }
+
+    /// Begin scan for the gsi_dispatch object (regional generation mix and energy flows)
+    fn begin_gsi_dispatch(&mut self) -> CorrentlyResult {
+        self.config.log_level.info(&format!(
+            "Fetching Corrently dispatch for postal code: {}",
+            self.postal_code
+        ));
+
+        let body = self.fetch_endpoint(Endpoint::Dispatch, &[("zip", self.postal_code.clone())])?;
+        self.parse_dispatch_response(&body)?;
+        stats::inc_stats(
+            FDW_NAME,
+            stats::Metric::RowsIn,
+            self.object_rows.len() as i64,
+        );
+
+        Ok(())
+    }
+
+    /// Parse the dispatch response from API response
+    /// Pattern: one row per generation source share and per import origin
+    /// Shape: {"zip", "timeframe": {"start", "end"}, "sources": {"<source>": share},
+    /// "dispatch_from": {"<origin>": {"energy"}}}, every field required
+    fn parse_dispatch_response(&mut self, body: &str) -> CorrentlyResult {
+        let resp_json: JsonValue = serde_json::from_str(body)
+            .map_err(|e| CorrentlyError::Parse(format!("JSON parse error: {}", e)))?;
+
+        let missing = |path: &str| {
+            CorrentlyError::Parse(format!(
+                "missing or invalid '{}' in dispatch response",
+                path
+            ))
+        };
+
+        // Dispatch period (timeframe.start / timeframe.end in milliseconds)
+        let timeframe = resp_json.get("timeframe");
+        let period_start = timeframe
+            .and_then(|tf| tf.get("start"))
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| missing("timeframe.start"))?;
+        let period_end = timeframe
+            .and_then(|tf| tf.get("end"))
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| missing("timeframe.end"))?;
+
+        let postal_code = resp_json
+            .get("zip")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| missing("zip"))?
+            .to_string();
+        let sources = resp_json
+            .get("sources")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| missing("sources"))?;
+        let imports = resp_json
+            .get("dispatch_from")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| missing("dispatch_from"))?;
+
+        // Generation mix: share per source type (percent)
+        for (source, share) in sources {
+            let share = share
+                .as_f64()
+                .ok_or_else(|| missing(&format!("sources.{}", source)))?;
+            self.object_rows.push(serde_json::json!({
+                "postal_code": postal_code,
+                "flow_type": "source",
+                "origin": source,
+                "energy_kwh": null,
+                "share_pct": share,
+                "period_start": period_start,
+                "period_end": period_end,
+            }));
+        }
+
+        // Imports: energy per origin region, share relative to all imports
+        let imports = imports
+            .iter()
+            .map(|(origin, flow)| {
+                flow.get("energy")
+                    .and_then(|v| v.as_f64())
+                    .map(|energy| (origin, energy))
+                    .ok_or_else(|| missing(&format!("dispatch_from.{}.energy", origin)))
+            })
+            .collect::<CorrentlyResult<Vec<_>>>()?;
+        let total_import: f64 = imports.iter().map(|(_, energy)| energy).sum();
+        for (origin, energy) in imports {
+            let share = if total_import > 0.0 {
+                energy / total_import * 100.0
+            } else {
+                0.0
+            };
+            self.object_rows.push(serde_json::json!({
+                "postal_code": postal_code,
+                "flow_type": "import",
+                "origin": origin,
+                "energy_kwh": energy,
+                "share_pct": share,
+                "period_start": period_start,
+                "period_end": period_end,
+            }));
+        }
+
+        self.config.log_level.info(&format!(
+            "Successfully parsed {} dispatch rows",
+            self.object_rows.len()
+        ));
+
+        Ok(())
+    }
+
+    /// Begin scan for the co2_footprint object (Scope 2 emissions for metered consumption)
+    fn begin_co2_footprint(&mut self, quals: &[Qual]) -> CorrentlyResult {
+        let energy_kwh = Self::extract_qual_f64(quals, "energy_kwh").ok_or_else(|| {
+            CorrentlyError::Validation(
+                "energy_kwh parameter is required in WHERE clause (e.g., WHERE energy_kwh = 1500)"
+                    .to_string(),
+            )
+        })?;
+        if energy_kwh <= 0.0 {
+            return Err(CorrentlyError::Validation(format!(
+                "energy_kwh must be positive, got {}",
+                energy_kwh
+            )));
+        }
+
+        // Time range (optional): period_start >= .. AND period_end <= ..
+        let from = Self::extract_qual_timestamp(quals, "period_start", &["=", ">="]);
+        let to = Self::extract_qual_timestamp(quals, "period_end", &["=", "<="]);
+
+        // API expects energy in watt-hours and timestamps in milliseconds
+        let mut params = vec![
+            ("zip", self.postal_code.clone()),
+            ("wh", ((energy_kwh * 1000.0).round() as i64).to_string()),
+        ];
+        if let Some(from) = from {
+            params.push(("from", from.to_string()));
+        }
+        if let Some(to) = to {
+            params.push(("to", to.to_string()));
+        }
+
+        self.config.log_level.info(&format!(
+            "Fetching Corrently CO2 footprint for postal code: {}, energy: {} kWh",
+            self.postal_code, energy_kwh
+        ));
+
+        let body = self.fetch_endpoint(Endpoint::Footprint, &params)?;
+        self.parse_footprint_response(&body, energy_kwh)?;
+        stats::inc_stats(
+            FDW_NAME,
+            stats::Metric::RowsIn,
+            self.object_rows.len() as i64,
+        );
+
+        Ok(())
+    }
+
+    /// Parse the CO2 footprint response from API response
+    /// Pattern: one row per source mix
+    /// Shape: {"co2_g_standard", "co2_g_oekostrom", "timeframe": {"start", "end"}}, every field required
+    fn parse_footprint_response(&mut self, body: &str, energy_kwh: f64) -> CorrentlyResult {
+        let resp_json: JsonValue = serde_json::from_str(body)
+            .map_err(|e| CorrentlyError::Parse(format!("JSON parse error: {}", e)))?;
+
+        let missing = |path: &str| {
+            CorrentlyError::Parse(format!(
+                "missing or invalid '{}' in CO2 footprint response",
+                path
+            ))
+        };
+        let timeframe = resp_json.get("timeframe");
+        let period_start = timeframe
+            .and_then(|tf| tf.get("start"))
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| missing("timeframe.start"))?;
+        let period_end = timeframe
+            .and_then(|tf| tf.get("end"))
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| missing("timeframe.end"))?;
+
+        // Corrently names the green mix "oekostrom" (German for green electricity)
+        for (key, mix) in [("co2_g_standard", "standard"), ("co2_g_oekostrom", "green")] {
+            let co2_g = resp_json
+                .get(key)
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| missing(key))?;
+            self.object_rows.push(serde_json::json!({
+                "postal_code": self.postal_code,
+                "energy_kwh": energy_kwh,
+                "mix": mix,
+                "co2_g": co2_g,
+                "co2_g_kwh": co2_g / energy_kwh,
+                "period_start": period_start,
+                "period_end": period_end,
+            }));
+        }
+
+        self.config.log_level.info(&format!(
+            "Successfully parsed {} CO2 footprint rows",
+            self.object_rows.len()
+        ));
+
+        Ok(())
+    }
+
+    /// Begin scan for the tariff_prices object (hourly dynamic tariff with price components)
+    fn begin_tariff_prices(&mut self, quals: &[Qual]) -> CorrentlyResult {
+        // Time range (optional): period_start >= .. AND period_start < ..
+        let from = Self::extract_qual_timestamp(quals, "period_start", &["=", ">=", ">"]);
+        let to = Self::extract_qual_timestamp(quals, "period_start", &["=", "<", "<="]);
+
+        let mut params = vec![("zip", self.postal_code.clone())];
+        if let Some(from) = from {
+            params.push(("from", from.to_string()));
+        }
+        if let Some(to) = to {
+            params.push(("to", to.to_string()));
+        }
+
+        self.config.log_level.info(&format!(
+            "Fetching Corrently tariff prices for postal code: {}",
+            self.postal_code
+        ));
+
+        let body = self.fetch_endpoint(Endpoint::TariffComponents, &params)?;
+        self.parse_tariff_response(&body)?;
+        stats::inc_stats(
+            FDW_NAME,
+            stats::Metric::RowsIn,
+            self.object_rows.len() as i64,
+        );
+
+        Ok(())
+    }
+
+    /// Read a required numeric field of a response array element
+    fn required_f64(obj: &JsonValue, key: &str, idx: usize) -> CorrentlyResult<f64> {
+        obj.get(key).and_then(|v| v.as_f64()).ok_or_else(|| {
+            CorrentlyError::Parse(format!("missing or invalid '{}' at index {}", key, idx))
+        })
+    }
+
+    /// Read a required integer field (e.g. an epoch ms timestamp) of a response array element
+    fn required_i64(obj: &JsonValue, key: &str, idx: usize) -> CorrentlyResult<i64> {
+        obj.get(key).and_then(|v| v.as_i64()).ok_or_else(|| {
+            CorrentlyError::Parse(format!("missing or invalid '{}' at index {}", key, idx))
+        })
+    }
+
+    /// Parse the tariff price array from API response
+    /// Pattern: one row per hour, each price component as its own column (EUR/kWh)
+    /// Shape: {"data": [{"start_timestamp", "end_timestamp", "exchange_price", "grid_fee",
+    /// "levies", "vat", "total"}]}, every field required
+    fn parse_tariff_response(&mut self, body: &str) -> CorrentlyResult {
+        let resp_json: JsonValue = serde_json::from_str(body)
+            .map_err(|e| CorrentlyError::Parse(format!("JSON parse error: {}", e)))?;
+
+        let data_array = resp_json
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| {
+                CorrentlyError::Parse(
+                    "missing or invalid 'data' array in tariff response".to_string(),
+                )
+            })?;
+
+        for (idx, price_obj) in data_array.iter().enumerate() {
+            self.object_rows.push(serde_json::json!({
+                "postal_code": self.postal_code,
+                "period_start": Self::required_i64(price_obj, "start_timestamp", idx)?,
+                "period_end": Self::required_i64(price_obj, "end_timestamp", idx)?,
+                "exchange_price_eur_kwh": Self::required_f64(price_obj, "exchange_price", idx)?,
+                "grid_fee_eur_kwh": Self::required_f64(price_obj, "grid_fee", idx)?,
+                "levies_eur_kwh": Self::required_f64(price_obj, "levies", idx)?,
+                "vat_eur_kwh": Self::required_f64(price_obj, "vat", idx)?,
+                "total_price_eur_kwh": Self::required_f64(price_obj, "total", idx)?,
+            }));
+        }
+
+        self.config.log_level.info(&format!(
+            "Successfully parsed {} tariff price rows",
+            self.object_rows.len()
+        ));
+
+        Ok(())
+    }
+
+    /// Begin scan for the gsi_advisor object (Corrently's hourly usage recommendation)
+    fn begin_gsi_advisor(&mut self, quals: &[Qual]) -> CorrentlyResult {
+        // Time range (optional): window_start >= .. AND window_start < ..
+        let from = Self::extract_qual_timestamp(quals, "window_start", &["=", ">=", ">"]);
+        let to = Self::extract_qual_timestamp(quals, "window_start", &["=", "<", "<="]);
+
+        let mut params = vec![("zip", self.postal_code.clone())];
+        if let Some(from) = from {
+            params.push(("from", from.to_string()));
+        }
+        if let Some(to) = to {
+            params.push(("to", to.to_string()));
+        }
+
+        self.config.log_level.info(&format!(
+            "Fetching Corrently advisor for postal code: {}",
+            self.postal_code
+        ));
+
+        let body = self.fetch_endpoint(Endpoint::Advisor, &params)?;
+        self.parse_advisor_response(&body)?;
+        stats::inc_stats(
+            FDW_NAME,
+            stats::Metric::RowsIn,
+            self.object_rows.len() as i64,
+        );
+
+        Ok(())
+    }
+
+    /// Parse the advisor recommendation array from API response
+    /// Pattern: one row per time window, recommendation and reason passed through verbatim
+    /// Shape: {"advice": [{"timeStamp", "end", "recommendation", "reason"}]}, every field required
+    fn parse_advisor_response(&mut self, body: &str) -> CorrentlyResult {
+        let resp_json: JsonValue = serde_json::from_str(body)
+            .map_err(|e| CorrentlyError::Parse(format!("JSON parse error: {}", e)))?;
+
+        let advice_array = resp_json
+            .get("advice")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| {
+                CorrentlyError::Parse(
+                    "missing or invalid 'advice' array in advisor response".to_string(),
+                )
+            })?;
+
+        let required_str = |obj: &JsonValue, key: &str, idx: usize| {
+            obj.get(key)
+                .and_then(|v| v.as_str())
+                .map(str::to_owned)
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid '{}' at index {}", key, idx))
+                })
+        };
+
+        for (idx, advice_obj) in advice_array.iter().enumerate() {
+            self.object_rows.push(serde_json::json!({
+                "postal_code": self.postal_code,
+                "window_start": Self::required_i64(advice_obj, "timeStamp", idx)?,
+                "window_end": Self::required_i64(advice_obj, "end", idx)?,
+                "recommendation": required_str(advice_obj, "recommendation", idx)?,
+                "reason": required_str(advice_obj, "reason", idx)?,
+            }));
+        }
+
+        self.config.log_level.info(&format!(
+            "Successfully parsed {} advisor rows",
+            self.object_rows.len()
+        ));
+
+        Ok(())
+    }
+
     /// Parse the forecast array from API response
     /// Pattern: Energy Charts array flattening (113 forecast objects → 113 rows)
-    fn parse_forecast_response(&mut self, body: &str) -> FdwResult {
-        let resp_json: JsonValue =
-            serde_json::from_str(body).map_err(|e| format!("JSON parse error: {}", e))?;
+    /// `array_key` is 'forecast' for gsi/prediction and 'history' for gsi/historic (same objects)
+    fn parse_forecast_response(&mut self, body: &str, array_key: &str) -> CorrentlyResult {
+        let resp_json: JsonValue = serde_json::from_str(body)
+            .map_err(|e| CorrentlyError::Parse(format!("JSON parse error: {}", e)))?;
 
-        // Extract forecast array
+        // Extract forecast array, naming the keys actually received on a shape mismatch
         let forecast_array = resp_json
-            .get("forecast")
+            .get(array_key)
             .and_then(|f| f.as_array())
-            .ok_or("missing or invalid 'forecast' array in response")?;
-
-        utils::report_info(&format!(
+            .ok_or_else(|| {
+                let received = resp_json
+                    .as_object()
+                    .map(|obj| obj.keys().cloned().collect::<Vec<_>>().join(", "))
+                    .unwrap_or_else(|| "not a JSON object".to_string());
+                CorrentlyError::Parse(format!(
+                    "missing or invalid '{}' array in response (received: {})",
+                    array_key, received
+                ))
+            })?;
+
+        self.config.log_level.info(&format!(
             "Parsing {} forecast objects from Corrently API",
             forecast_array.len()
         ));
@@ -155,7 +1544,12 @@ impl CorrentlyFdw {
             let forecast_start_time_val = forecast_obj
                 .get("timeStamp")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'timeStamp' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'timeStamp' at index {}",
+                        idx
+                    ))
+                })?;
             self.forecast_start_time.push(forecast_start_time_val);
 
             // forecast_period_start (timeframe.start in milliseconds)
@@ -163,7 +1557,12 @@ impl CorrentlyFdw {
                 .get("timeframe")
                 .and_then(|tf| tf.get("start"))
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'timeframe.start' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'timeframe.start' at index {}",
+                        idx
+                    ))
+                })?;
             self.forecast_period_start.push(forecast_period_start_val);
 
             // forecast_period_end (timeframe.end in milliseconds)
@@ -171,63 +1570,87 @@ impl CorrentlyFdw {
                 .get("timeframe")
                 .and_then(|tf| tf.get("end"))
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'timeframe.end' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'timeframe.end' at index {}",
+                        idx
+                    ))
+                })?;
             self.forecast_period_end.push(forecast_period_end_val);
 
             // green_energy_index (GrünstromIndex value 0-100)
             let green_energy_index_val = forecast_obj
                 .get("gsi")
                 .and_then(|v| v.as_f64())
-                .ok_or_else(|| format!("missing or invalid 'gsi' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'gsi' at index {}", idx))
+                })?;
             self.green_energy_index.push(green_energy_index_val);
 
             // renewable_energy_pct (total renewable energy percentage)
             let renewable_energy_pct_val = forecast_obj
                 .get("eevalue")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'eevalue' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'eevalue' at index {}", idx))
+                })?;
             self.renewable_energy_pct.push(renewable_energy_pct_val);
 
             // wind_energy_pct (wind energy percentage)
             let wind_energy_pct_val = forecast_obj
                 .get("ewind")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'ewind' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'ewind' at index {}", idx))
+                })?;
             self.wind_energy_pct.push(wind_energy_pct_val);
 
             // solar_energy_pct (solar energy percentage)
             let solar_energy_pct_val = forecast_obj
                 .get("esolar")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'esolar' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'esolar' at index {}", idx))
+                })?;
             self.solar_energy_pct.push(solar_energy_pct_val);
 
             // net_wind_energy_pct (net wind energy percentage)
             let net_wind_energy_pct_val = forecast_obj
                 .get("enwind")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'enwind' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'enwind' at index {}", idx))
+                })?;
             self.net_wind_energy_pct.push(net_wind_energy_pct_val);
 
             // net_solar_energy_pct (net solar energy percentage)
             let net_solar_energy_pct_val = forecast_obj
                 .get("ensolar")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'ensolar' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'ensolar' at index {}", idx))
+                })?;
             self.net_solar_energy_pct.push(net_solar_energy_pct_val);
 
             // smart_city_index (Smart City Index 0-100)
             let smart_city_index_val = forecast_obj
                 .get("sci")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'sci' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'sci' at index {}", idx))
+                })?;
             self.smart_city_index.push(smart_city_index_val);
 
             // energy_price_eur_kwh (CRITICAL: This is a STRING in API, needs parsing!)
             let energy_price_str = forecast_obj
                 .get("energyprice")
                 .and_then(|v| v.as_str())
-                .ok_or_else(|| format!("missing or invalid 'energyprice' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'energyprice' at index {}",
+                        idx
+                    ))
+                })?;
             let energy_price_val: f64 = energy_price_str.parse().unwrap_or(0.0); // Default to 0.0 if parsing fails
             self.energy_price_eur_kwh.push(energy_price_val);
 
@@ -235,28 +1658,42 @@ impl CorrentlyFdw {
             let co2_baseline_val = forecast_obj
                 .get("co2_avg")
                 .and_then(|v| v.as_f64())
-                .ok_or_else(|| format!("missing or invalid 'co2_avg' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'co2_avg' at index {}", idx))
+                })?;
             self.co2_baseline_g_kwh.push(co2_baseline_val);
 
             // standard_mix_co2_g_kwh (CO2 for standard energy mix)
             let standard_mix_co2_val = forecast_obj
                 .get("co2_g_standard")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'co2_g_standard' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'co2_g_standard' at index {}",
+                        idx
+                    ))
+                })?;
             self.standard_mix_co2_g_kwh.push(standard_mix_co2_val);
 
             // green_mix_co2_g_kwh (CO2 for green energy mix)
             let green_mix_co2_val = forecast_obj
                 .get("co2_g_oekostrom")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'co2_g_oekostrom' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!(
+                        "missing or invalid 'co2_g_oekostrom' at index {}",
+                        idx
+                    ))
+                })?;
             self.green_mix_co2_g_kwh.push(green_mix_co2_val);
 
             // postal_code (German postal code, 5 digits)
             let postal_code_val = forecast_obj
                 .get("zip")
                 .and_then(|v| v.as_str())
-                .ok_or_else(|| format!("missing or invalid 'zip' at index {}", idx))?
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'zip' at index {}", idx))
+                })?
                 .to_string();
             self.postal_code_values.push(postal_code_val);
 
@@ -264,11 +1701,13 @@ impl CorrentlyFdw {
             let forecast_created_at_val = forecast_obj
                 .get("iat")
                 .and_then(|v| v.as_i64())
-                .ok_or_else(|| format!("missing or invalid 'iat' at index {}", idx))?;
+                .ok_or_else(|| {
+                    CorrentlyError::Parse(format!("missing or invalid 'iat' at index {}", idx))
+                })?;
             self.forecast_created_at.push(forecast_created_at_val);
         }
 
-        utils::report_info(&format!(
+        self.config.log_level.info(&format!(
             "Successfully parsed {} forecast rows",
             self.row_count()
         ));
@@ -277,12 +1716,12 @@ impl CorrentlyFdw {
     }
 
     /// Map column name to cell value for current row
-    fn get_cell_value(&self, tgt_col: &Column) -> Result<Option<Cell>, FdwError> {
+    fn get_cell_value(&self, tgt_col: &Column) -> CorrentlyResult<Option<Cell>> {
         let row_idx = self.current_row;
 
         // Bounds check
         if row_idx >= self.row_count() {
-            return Err("row index out of bounds".to_owned());
+            return Err(CorrentlyError::Parse("row index out of bounds".to_owned()));
         }
 
         // Map column name to stored data using safe .get() pattern
@@ -344,12 +1783,279 @@ impl CorrentlyFdw {
                 .postal_code_values
                 .get(row_idx)
                 .map(|v| Cell::String(v.clone())),
+            "site_label" => self
+                .postal_code_values
+                .get(row_idx)
+                .map(|v| Cell::String(self.site_label(v))),
 
-            _ => return Err(format!("unknown column '{}'", tgt_col.name())),
+            // Region lookup (embedded postal code prefix table)
+            "federal_state" => self
+                .postal_code_values
+                .get(row_idx)
+                .and_then(|pc| plz::region(pc))
+                .map(|r| Cell::String(r.federal_state.to_string())),
+            "city" => self
+                .postal_code_values
+                .get(row_idx)
+                .and_then(|pc| plz::region(pc))
+                .map(|r| Cell::String(r.city.to_string())),
+            "tso_zone" => self
+                .postal_code_values
+                .get(row_idx)
+                .and_then(|pc| plz::region(pc))
+                .and_then(|r| plz::tso_zone(r.federal_state))
+                .map(|tso| Cell::String(tso.to_string())),
+
+            // Resampling parameter (echoed so the WHERE clause matches)
+            "resolution" => Some(Cell::String(
+                self.resolution
+                    .as_ref()
+                    .map_or_else(|| "hour".to_string(), |r| r.label.clone()),
+            )),
+            "interpolation" => Some(Cell::String(self.interpolation.as_str().to_string())),
+            "aggregation" => Some(Cell::String(self.aggregation.as_str().to_string())),
+
+            _ => {
+                return Err(CorrentlyError::Config(format!(
+                    "unknown column '{}'",
+                    tgt_col.name()
+                )))
+            }
         };
 
         Ok(cell)
     }
+
+    /// Validate an inserted meter reading and convert it to the API payload
+    /// Pattern: columns are matched by name, so the column order of the table does not matter
+    fn meter_reading_from_row(row: &Row) -> Result<JsonValue, String> {
+        let mut meter_id = None;
+        let mut reading_time = None;
+        let mut reading_kwh = None;
+
+        for (col, cell) in row.cols().iter().zip(row.cells()) {
+            match (col.as_str(), cell) {
+                ("meter_id", Some(Cell::String(id))) => meter_id = Some(id),
+                ("meter_id", Some(Cell::I64(id))) => meter_id = Some(id.to_string()),
+                ("meter_id", Some(Cell::I32(id))) => meter_id = Some(id.to_string()),
+                ("reading_time", Some(Cell::Timestamptz(us) | Cell::Timestamp(us))) => {
+                    reading_time = Some(us / 1000)
+                }
+                ("reading_kwh", Some(Cell::Numeric(v) | Cell::F64(v))) => reading_kwh = Some(v),
+                ("reading_kwh", Some(Cell::F32(v))) => reading_kwh = Some(v as f64),
+                ("reading_kwh", Some(Cell::I64(v))) => reading_kwh = Some(v as f64),
+                ("reading_kwh", Some(Cell::I32(v))) => reading_kwh = Some(v as f64),
+                _ => {}
+            }
+        }
+
+        let meter_id = meter_id
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .ok_or("meter_id is required")?;
+        let reading_time = reading_time.ok_or("reading_time is required")?;
+        let reading_kwh = reading_kwh.ok_or("reading_kwh is required")?;
+
+        if !reading_kwh.is_finite() || reading_kwh < 0.0 {
+            return Err(format!(
+                "reading_kwh must be a non-negative number (got {})",
+                reading_kwh
+            ));
+        }
+        if reading_time > time::epoch_secs() * 1000 + METER_READING_MAX_FUTURE_MS {
+            return Err("reading_time must not be in the future".to_string());
+        }
+
+        Ok(serde_json::json!({
+            "meterId": meter_id,
+            "timeStamp": reading_time,
+            "reading": reading_kwh,
+        }))
+    }
+
+    /// Convert an inserted row to the API payload of the current write object
+    fn modify_payload_from_row(&self, row: &Row) -> Result<JsonValue, String> {
+        match self.object.as_str() {
+            "meter_readings" => Self::meter_reading_from_row(row),
+            other => Err(format!("object '{}' is not writable", other)),
+        }
+    }
+
+    /// Submit one batch of buffered rows to the write object's endpoint
+    /// Pattern: shared by all write objects (one POST per batch, rejected rows recorded)
+    fn flush_batch(&mut self, mut batch: modify::Batch) -> CorrentlyResult {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let &(_, endpoint, key, _) = WRITE_OBJECTS
+            .iter()
+            .find(|(object, _, _, _)| *object == self.object)
+            .ok_or_else(|| {
+                CorrentlyError::Config(format!("object '{}' is not writable", self.object))
+            })?;
+
+        self.config.log_level.info(&format!(
+            "Submitting {} ({})",
+            self.object,
+            batch.describe_rows()
+        ));
+
+        // Row key (rowid_column value) of every submitted row, to match rejected entries
+        let row_keys: Vec<(JsonValue, usize)> = batch
+            .payloads
+            .iter()
+            .zip(&batch.row_numbers)
+            .map(|(payload, &row)| {
+                (
+                    payload
+                        .get(&self.rowid_field)
+                        .cloned()
+                        .unwrap_or(JsonValue::Null),
+                    row,
+                )
+            })
+            .collect();
+
+        let mut envelope = serde_json::Map::new();
+        envelope.insert(
+            key.to_string(),
+            JsonValue::Array(std::mem::take(&mut batch.payloads)),
+        );
+        let count = batch.row_numbers.len();
+
+        let resp_body = self
+            .post_endpoint(endpoint, JsonValue::Object(envelope).to_string())
+            .map_err(|err| {
+                err.context(format!(
+                    "submitting {} failed ({} of {} rows submitted before)",
+                    batch.describe_rows(),
+                    self.modify.submitted(),
+                    self.modify.row_count()
+                ))
+            })?;
+
+        let rejected = self.rejected_from_response(&resp_body, &row_keys);
+        self.modify.record_submitted(count, rejected.len());
+        for (row, reason) in rejected {
+            self.modify.reject(row, &reason);
+        }
+
+        Ok(())
+    }
+
+    /// Collect rows rejected by the API from the submission response
+    /// Accepted shape: {"rejected": [{"<rowid field>": key, "reason": "..."}]}, e.g.
+    /// {"meterId": "meter-001", ...} with rowid_column 'meter_id'; every submitted row with
+    /// that key is reported (an unknown key is reported by its value)
+    fn rejected_from_response(
+        &self,
+        body: &str,
+        row_keys: &[(JsonValue, usize)],
+    ) -> Vec<(String, String)> {
+        let resp_json: JsonValue = match serde_json::from_str(body) {
+            Ok(json) => json,
+            Err(_) => return Vec::new(),
+        };
+        let Some(rejected) = resp_json.get("rejected").and_then(|r| r.as_array()) else {
+            return Vec::new();
+        };
+
+        let mut rows = Vec::new();
+        for item in rejected {
+            let reason = item
+                .get("reason")
+                .and_then(|r| r.as_str())
+                .unwrap_or("rejected by API");
+            let key = item.get(&self.rowid_field).unwrap_or(&JsonValue::Null);
+            let matching: Vec<String> = row_keys
+                .iter()
+                .filter(|(row_key, _)| !key.is_null() && row_key == key)
+                .map(|(_, row)| row.to_string())
+                .collect();
+            if matching.is_empty() {
+                rows.push((
+                    format!("? ({} {})", self.rowid_field, key),
+                    reason.to_string(),
+                ));
+            } else {
+                rows.extend(matching.into_iter().map(|row| (row, reason.to_string())));
+            }
+        }
+        rows
+    }
+
+    /// Map column name to cell value for current derived object row
+    /// Pattern: JSON row keyed by column name, converted by target column type
+    fn get_object_cell_value(&self, tgt_col: &Column) -> CorrentlyResult<Option<Cell>> {
+        let row = self
+            .object_rows
+            .get(self.current_row)
+            .ok_or_else(|| CorrentlyError::Parse("row index out of bounds".to_owned()))?;
+
+        let value = match row.get(tgt_col.name()) {
+            Some(v) if !v.is_null() => v,
+            _ => return Ok(None),
+        };
+
+        // Values that do not fit the column type fail instead of being truncated or dropped
+        let mismatch = |sql_type: &str| {
+            CorrentlyError::Parse(format!(
+                "value {} of column '{}' does not fit type {}",
+                value,
+                tgt_col.name(),
+                sql_type
+            ))
+        };
+        let int = |sql_type: &str| value.as_i64().ok_or_else(|| mismatch(sql_type));
+        let float = |sql_type: &str| value.as_f64().ok_or_else(|| mismatch(sql_type));
+
+        // CRITICAL: Temporal fields are stored as milliseconds → microseconds for TIMESTAMP WITH TIME ZONE
+        let micros = |sql_type: &str| {
+            int(sql_type)?
+                .checked_mul(1000)
+                .ok_or_else(|| mismatch(sql_type))
+        };
+        let cell = match tgt_col.type_oid() {
+            TypeOid::Bool => Cell::Bool(value.as_bool().ok_or_else(|| mismatch("boolean"))?),
+            TypeOid::I8 => {
+                Cell::I8(i8::try_from(int("\"char\"")?).map_err(|_| mismatch("\"char\""))?)
+            }
+            TypeOid::I16 => {
+                Cell::I16(i16::try_from(int("smallint")?).map_err(|_| mismatch("smallint"))?)
+            }
+            TypeOid::I32 => {
+                Cell::I32(i32::try_from(int("integer")?).map_err(|_| mismatch("integer"))?)
+            }
+            TypeOid::I64 => Cell::I64(int("bigint")?),
+            TypeOid::F32 => {
+                let v = float("real")?;
+                if v.abs() > f32::MAX as f64 {
+                    return Err(mismatch("real"));
+                }
+                Cell::F32(v as f32)
+            }
+            TypeOid::F64 => Cell::F64(float("double precision")?),
+            TypeOid::Numeric => Cell::Numeric(float("numeric")?),
+            TypeOid::String => Cell::String(
+                value
+                    .as_str()
+                    .map_or_else(|| value.to_string(), str::to_owned),
+            ),
+            TypeOid::Date => Cell::Date(int("date")?.div_euclid(1000)),
+            TypeOid::Timestamp => Cell::Timestamp(micros("timestamp")?),
+            TypeOid::Timestamptz => Cell::Timestamptz(micros("timestamp with time zone")?),
+            TypeOid::Json => Cell::Json(value.to_string()),
+            _ => {
+                return Err(CorrentlyError::Config(format!(
+                    "unsupported type for column '{}'",
+                    tgt_col.name()
+                )))
+            }
+        };
+
+        Ok(Some(cell))
+    }
 }
 
 impl Guest for CorrentlyFdw {
@@ -360,42 +2066,18 @@ impl Guest for CorrentlyFdw {
 
     fn init(ctx: &Context) -> FdwResult {
         Self::init_instance();
-        let this = Self::this_mut();
-
-        // Extract server options
-        let opts = ctx.get_options(&OptionsType::Server);
-
-        // Extract API key (required) - supports Vault (recommended) or plain text (deprecated)
-        this.api_key = if let Some(vault_id) = opts.get("api_key_id") {
-            // Vault reference - secure method (RECOMMENDED)
-            utils::get_vault_secret(&vault_id)
-                .ok_or("Failed to retrieve API key from Vault. Ensure the secret exists and is accessible.")?
-        } else if let Some(plain_key) = opts.get("api_key") {
-            // Plain text - deprecated but supported for backward compatibility
-            utils::report_warning(
-                "Using plain text 'api_key' is deprecated for security reasons. \
-                 Please migrate to 'api_key_id' with Vault. \
-                 See: https://supabase.com/docs/guides/database/vault"
-            );
-            plain_key.clone()
-        } else {
-            return Err("Either 'api_key' or 'api_key_id' must be provided in server options".to_string());
-        };
-
-        // Extract base URL (optional, with default)
-        this.base_url = opts.require_or("api_url", "https://api.corrently.io");
 
-        // Set up HTTP headers
-        this.headers.push((
-            "user-agent".to_owned(),
-            "Supabase Wrappers Corrently FDW".to_string(),
-        ));
-        this.headers
-            .push(("accept".to_owned(), "application/json".to_string()));
+        // Validate all server and table options up front, reporting every problem at once
+        validate::validate_options(ctx).map_err(CorrentlyError::Config)?;
 
-        utils::report_info(&format!(
+        // Connection settings are resolved per scan (table options can override the server)
+        let log_level = config::option(ctx, "log_level")
+            .and_then(|v| LogLevel::parse(&v).ok())
+            .unwrap_or_default();
+        log_level.info(&format!(
             "Corrently FDW initialized with base URL: {}",
-            this.base_url
+            Self::table_or_server_option(ctx, "api_url")
+                .unwrap_or_else(|| config::DEFAULT_API_URL.to_string())
         ));
         stats::inc_stats(FDW_NAME, stats::Metric::CreateTimes, 1);
 
@@ -404,62 +2086,127 @@ impl Guest for CorrentlyFdw {
 
     fn begin_scan(ctx: &Context) -> FdwResult {
         let this = Self::this_mut();
+        this.scan_started = time::epoch_secs();
+        this.rows_out = 0;
 
         // Clear any previous data
         this.clear_data();
 
-        // Extract WHERE clause parameters
-        let quals = ctx.get_quals();
-
-        // Extract postal_code (required)
-        this.postal_code = Self::extract_qual_string(&quals, "postal_code").ok_or(
-            "postal_code parameter is required in WHERE clause (e.g., WHERE postal_code = '69168')",
-        )?;
-
-        // Extract hours (optional)
-        this.hours = Self::extract_qual_i64(&quals, "hours");
-
-        // Build API URL
-        let mut url = format!(
-            "{}/v2.0/gsi/prediction?zip={}&token={}",
-            this.base_url, this.postal_code, this.api_key
-        );
-
-        if let Some(hours_val) = this.hours {
-            url.push_str(&format!("&hours={}", hours_val));
+        // Extract target object (table option, defaults to gsi_prediction)
+        this.object = ctx
+            .get_options(&OptionsType::Table)
+            .require_or("object", "gsi_prediction");
+        this.load_config(ctx)?;
+        if this.object == "meter_readings" {
+            return Err(CorrentlyError::Config(
+                "meter_readings is write-only (use INSERT to submit readings)".to_string(),
+            )
+            .into());
         }
 
-        utils::report_info(&format!(
-            "Fetching Corrently forecast for postal code: {}, hours: {:?}",
-            this.postal_code, this.hours
-        ));
+        // The status object pings with the configured postal code (health check of the connection settings)
+        if this.object == "status" {
+            this.begin_status(ctx)?;
+            this.current_row = 0;
+            return Ok(());
+        }
 
-        // Make HTTP request
-        let req = http::Request {
-            method: http::Method::Get,
-            url,
-            headers: this.headers.clone(),
-            body: String::default(),
+        // Extract WHERE clause parameters
+        let quals = ctx.get_quals();
+        this.log_quals(&quals);
+
+        // A postal_codes site list (gsi_prediction) bounds the scan: a postal_code condition
+        // selects from it (no rows for other codes) and federal_state is evaluated locally.
+        // Otherwise extract postal_code (required), expand federal_state for gsi_prediction,
+        // or fall back to the postal_code table option (one foreign table per site)
+        let postal_code = Self::extract_qual_string(&quals, "postal_code");
+        let federal_state = Self::extract_qual_string(&quals, "federal_state");
+        let table_opts = ctx.get_options(&OptionsType::Table);
+        let site_list = table_opts.get("postal_codes");
+        let default_postal_code = table_opts.get("postal_code");
+        this.postal_codes = match (postal_code, federal_state, site_list, default_postal_code) {
+            (postal_code, _, Some(list), _) if this.object == "gsi_prediction" => {
+                this.site_labels = Self::parse_site_list(&list, table_opts.get("site_labels"))
+                    .map_err(CorrentlyError::Config)?;
+                this.site_labels
+                    .iter()
+                    .map(|(pc, _)| pc.clone())
+                    .filter(|pc| postal_code.as_ref().is_none_or(|wanted| wanted.trim() == pc))
+                    .collect()
+            }
+            (Some(postal_code), _, _, _) => vec![postal_code],
+            (None, Some(state), _, _) if this.object == "gsi_prediction" => {
+                plz::representative_postal_codes(&state)
+                    .map_err(CorrentlyError::Validation)?
+                    .iter()
+                    .map(|pc| pc.to_string())
+                    .collect()
+            }
+            (None, _, _, Some(postal_code)) => vec![postal_code.trim().to_string()],
+            (None, _, Some(_), None) => {
+                return Err(CorrentlyError::Config(format!(
+                    "postal_codes table option is only supported by object 'gsi_prediction' (got '{}')",
+                    this.object
+                ))
+                .into())
+            }
+            _ => return Err(CorrentlyError::Validation(
+                "postal_code parameter is required in WHERE clause (e.g., WHERE postal_code = '69168') \
+                 or as table option (OPTIONS (postal_code '69168'))"
+                    .to_string(),
+            )
+            .into()),
         };
+        this.postal_code = this.postal_codes.first().cloned().unwrap_or_default();
 
-        let resp = http::get(&req)?;
+        // Validate postal codes before calling the API (fail fast, saves quota)
+        if this.config.validate_postal_code {
+            for postal_code in &this.postal_codes {
+                plz::validate(postal_code).map_err(CorrentlyError::Validation)?;
+            }
+        }
 
-        // Check for HTTP errors
-        http::error_for_status(&resp)
-            .map_err(|err| format!("Corrently API error: {} - {}", err, resp.body))?;
+        // Dispatch to object-specific scan
+        match this.object.as_str() {
+            "gsi_prediction" => this.begin_gsi_prediction(ctx, &quals)?,
+            "co2_savings" => this.begin_co2_savings(&quals)?,
+            "gsi_events" => this.begin_gsi_events(&quals)?,
+            "gsi_dispatch" => this.begin_gsi_dispatch()?,
+            "co2_footprint" => this.begin_co2_footprint(&quals)?,
+            "gsi_history" => this.begin_gsi_history(ctx, &quals)?,
+            "tariff_prices" => this.begin_tariff_prices(&quals)?,
+            "gsi_advisor" => this.begin_gsi_advisor(&quals)?,
+            other => {
+                return Err(
+                    CorrentlyError::Config(match validate::suggest(other, OBJECTS) {
+                        Some(candidate) => {
+                            format!(
+                                "unsupported object '{}', did you mean '{}'?",
+                                other, candidate
+                            )
+                        }
+                        None => format!(
+                            "unsupported object '{}' (expected one of: {})",
+                            other,
+                            OBJECTS.join(", ")
+                        ),
+                    })
+                    .into(),
+                )
+            }
+        }
 
-        utils::report_info(&format!(
-            "Corrently API response: {} bytes, status {}",
-            resp.body.len(),
-            resp.status_code
+        this.config.log_level.info(&format!(
+            "Corrently scan sent {} API requests for {} postal code(s)",
+            this.rate_limiter.requests(),
+            this.postal_codes.len()
+        ));
+        this.config.log_level.debug(&format!(
+            "{} scan prepared {} rows in {} s",
+            this.object,
+            this.scan_row_count(),
+            (time::epoch_secs() - this.scan_started).max(0)
         ));
-
-        // Parse JSON response
-        this.parse_forecast_response(&resp.body)?;
-
-        // Track stats
-        stats::inc_stats(FDW_NAME, stats::Metric::BytesIn, resp.body.len() as i64);
-        stats::inc_stats(FDW_NAME, stats::Metric::RowsIn, this.row_count() as i64);
 
         // Reset row iterator
         this.current_row = 0;
@@ -471,46 +2218,137 @@ impl Guest for CorrentlyFdw {
         let this = Self::this_mut();
 
         // Check if we've exhausted all rows
-        if this.current_row >= this.row_count() {
-            stats::inc_stats(FDW_NAME, stats::Metric::RowsOut, this.current_row as i64);
+        if this.current_row >= this.scan_row_count() {
             return Ok(None);
         }
 
         // Populate row with values for current row
         for tgt_col in ctx.get_columns() {
-            let cell = this.get_cell_value(&tgt_col)?;
+            let cell = match this.object.as_str() {
+                "gsi_prediction" | "gsi_history" => this.get_cell_value(&tgt_col)?,
+                _ => this.get_object_cell_value(&tgt_col)?,
+            };
             row.push(cell.as_ref());
         }
 
         // Move to next row
         this.current_row += 1;
+        this.rows_out += 1;
         Ok(Some(0))
     }
 
     fn end_scan(_ctx: &Context) -> FdwResult {
         let this = Self::this_mut();
+
+        // Rows are counted here so LIMIT and cancelled scans are accounted for as well
+        let duration_secs = (time::epoch_secs() - this.scan_started).max(0);
+        stats::inc_stats(FDW_NAME, stats::Metric::RowsOut, this.rows_out as i64);
+        this.metrics
+            .record_scan(&this.object, this.rows_out, duration_secs);
+        this.metrics.flush();
+        this.config.log_level.info(&format!(
+            "Corrently {} scan returned {} rows in {} s",
+            this.object, this.rows_out, duration_secs
+        ));
+
         this.clear_data();
         Ok(())
     }
 
-    fn begin_modify(_ctx: &Context) -> FdwResult {
-        Err("modify operations on foreign table are not supported".to_owned())
+    fn begin_modify(ctx: &Context) -> FdwResult {
+        let this = Self::this_mut();
+
+        // Only write objects accept modifications; forecast objects are read-only
+        this.object = ctx
+            .get_options(&OptionsType::Table)
+            .require_or("object", "gsi_prediction");
+        this.load_config(ctx)?;
+        let Some(&(_, _, _, fields)) = WRITE_OBJECTS
+            .iter()
+            .find(|(object, _, _, _)| *object == this.object)
+        else {
+            return Err(CorrentlyError::Config(format!(
+                "modify operations are not supported on object '{}' (writable objects: {})",
+                this.object,
+                WRITE_OBJECTS
+                    .iter()
+                    .map(|(object, _, _, _)| *object)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+            .into());
+        };
+
+        // Rows rejected by the API are matched by the rowid_column value, not by position
+        let rowid_column = ctx
+            .get_options(&OptionsType::Table)
+            .get("rowid_column")
+            .unwrap_or_default();
+        this.rowid_field = fields
+            .iter()
+            .find(|(column, _)| *column == rowid_column.trim())
+            .map(|(_, field)| field.to_string())
+            .ok_or_else(|| {
+                CorrentlyError::Config(format!(
+                    "{} requires the rowid_column table option, one of: {}",
+                    this.object,
+                    fields
+                        .iter()
+                        .map(|(column, _)| *column)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })?;
+
+        this.modify = modify::ModifyBuffer::new(
+            config::BATCH_SIZE.value(ctx)?,
+            config::MAX_BATCH_BYTES.value(ctx)?,
+        );
+
+        Ok(())
     }
 
-    fn insert(_ctx: &Context, _row: &Row) -> FdwResult {
+    fn insert(_ctx: &Context, row: &Row) -> FdwResult {
+        let this = Self::this_mut();
+
+        // Invalid rows are recorded (not submitted) so all rejected rows are reported together
+        let payload = this.modify_payload_from_row(row);
+        if let Some(batch) = this.modify.push(payload) {
+            this.flush_batch(batch)?;
+        }
+
         Ok(())
     }
 
     fn update(_ctx: &Context, _rowid: Cell, _row: &Row) -> FdwResult {
-        Ok(())
+        let this = Self::this_mut();
+        Err(CorrentlyError::Validation(format!(
+            "UPDATE is not supported on {} (submitted rows are immutable)",
+            this.object
+        ))
+        .into())
     }
 
     fn delete(_ctx: &Context, _rowid: Cell) -> FdwResult {
-        Ok(())
+        let this = Self::this_mut();
+        Err(CorrentlyError::Validation(format!(
+            "DELETE is not supported on {} (submitted rows are immutable)",
+            this.object
+        ))
+        .into())
     }
 
     fn end_modify(_ctx: &Context) -> FdwResult {
-        Ok(())
+        let this = Self::this_mut();
+
+        // Submit the remaining rows, then report every rejected row
+        let batch = this.modify.take_batch();
+        this.flush_batch(batch)?;
+        this.metrics.flush();
+
+        this.modify
+            .finish(&this.object)
+            .map_err(|err| CorrentlyError::Validation(err).into())
     }
 
     fn re_scan(_ctx: &Context) -> FdwResult {
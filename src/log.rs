@@ -0,0 +1,59 @@
+// Log verbosity (`log_level` server or table option)
+//
+// - `warn`: only warnings (e.g. deprecated options)
+// - `info` (default): one line per scan step (fetches, parsed rows, resampling)
+// - `debug`: additionally the redacted request, pushed-down vs. local quals,
+//   request / scan timing and a truncated response body preview
+
+use crate::bindings::supabase::wrappers::utils;
+
+/// Maximum number of characters of a response body shown in debug logs
+const BODY_PREVIEW_CHARS: usize = 300;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    #[default]
+    Info,
+    Warn,
+}
+
+impl LogLevel {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            other => Err(format!(
+                "invalid log_level '{}' (expected 'debug', 'info' or 'warn')",
+                other
+            )),
+        }
+    }
+
+    /// Report an informational message (suppressed at `warn`)
+    pub fn info(self, msg: &str) {
+        if self <= Self::Info {
+            utils::report_info(msg);
+        }
+    }
+
+    /// Report a debug message (only at `debug`)
+    pub fn debug(self, msg: &str) {
+        if self == Self::Debug {
+            utils::report_info(&format!("[debug] {}", msg));
+        }
+    }
+
+    pub fn is_debug(self) -> bool {
+        self == Self::Debug
+    }
+}
+
+/// First characters of a response body for debug logs
+pub fn body_preview(body: &str) -> String {
+    match body.char_indices().nth(BODY_PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}... ({} bytes)", &body[..end], body.len()),
+        None => body.to_string(),
+    }
+}
@@ -0,0 +1,152 @@
+// FDW metrics kept in the wrappers stats metadata
+//
+// `stats::inc_stats` only supports the fixed metrics (rows, bytes, create
+// times). Additional counters and last-fetch diagnostics are stored as a JSON
+// document in the stats metadata of the FDW, visible in
+// `extensions.wrappers_fdw_stats`:
+//
+//   {
+//     "last_success": 1761660000,            (epoch seconds)
+//     "last_failure": 1761650000,
+//     "last_error": "CORRENTLY-E005 upstream: ...",
+//     "requests": {"gsi_prediction": 40, "gsi_history": 2},
+//     "scans": {"gsi_prediction": 35},
+//     "last_scan": {"object": "gsi_prediction", "rows": 10, "duration_secs": 1, "finished": 1761660000},
+//     "aborted_requests": {"timeout": 1, "oversized": 0},
+//     "latency": {"le_1s": 40, "le_5s": 2, "le_30s": 0, "gt_30s": 0}
+//   }
+//
+// Latency buckets count API requests (retries included) by whole seconds, the
+// resolution of the host clock: `le_1s` holds everything that finished within
+// the same or the next clock second.
+//
+// Updates are collected in `Metrics` during a scan / modify and merged into
+// the document once (`flush`), so each statement does a single metadata
+// read-modify-write instead of one per request.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value as JsonValue;
+
+use crate::bindings::supabase::wrappers::{stats, time};
+use crate::FDW_NAME;
+
+/// Upper bounds (seconds) of the latency histogram buckets, the last bucket is open
+const LATENCY_BUCKETS_SECS: &[i64] = &[1, 5, 30];
+
+/// Read the metadata document (an empty object if unset or not JSON)
+fn load() -> serde_json::Map<String, JsonValue> {
+    stats::get_metadata(FDW_NAME)
+        .and_then(|doc| serde_json::from_str::<JsonValue>(&doc).ok())
+        .and_then(|doc| match doc {
+            JsonValue::Object(map) => Some(map),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn store(doc: serde_json::Map<String, JsonValue>) {
+    stats::set_metadata(FDW_NAME, &Some(JsonValue::Object(doc).to_string()));
+}
+
+/// Update a counter group of the metadata document (reset if not an object)
+fn update_group(
+    doc: &mut serde_json::Map<String, JsonValue>,
+    name: &str,
+    update: impl FnOnce(&mut serde_json::Map<String, JsonValue>),
+) {
+    let mut counters = match doc.remove(name) {
+        Some(JsonValue::Object(map)) => map,
+        _ => Default::default(),
+    };
+    update(&mut counters);
+    doc.insert(name.to_string(), JsonValue::Object(counters));
+}
+
+fn add(counters: &mut serde_json::Map<String, JsonValue>, key: &str, n: i64) {
+    let count = counters.get(key).and_then(|v| v.as_i64()).unwrap_or(0);
+    counters.insert(key.to_string(), JsonValue::from(count + n));
+}
+
+/// Metric updates of the current scan / modify, not yet written to the metadata
+#[derive(Debug, Default)]
+pub struct Metrics {
+    last_success: Option<i64>,
+    last_failure: Option<(i64, String)>,
+    last_scan: Option<JsonValue>,
+    /// Counter increments by (group, key)
+    counters: BTreeMap<(&'static str, String), i64>,
+}
+
+impl Metrics {
+    /// Increment a counter in a group of the metadata document (`{group: {key: n}}`)
+    pub fn increment(&mut self, group: &'static str, key: &str) {
+        *self.counters.entry((group, key.to_string())).or_default() += 1;
+    }
+
+    /// Record a successful API request of an object
+    pub fn record_success(&mut self, object: &str) {
+        self.last_success = Some(time::epoch_secs());
+        self.increment("requests", object);
+    }
+
+    /// Record a failed API request of an object with its error message
+    pub fn record_failure(&mut self, object: &str, error: &str) {
+        self.last_failure = Some((time::epoch_secs(), error.to_string()));
+        self.increment("requests", object);
+    }
+
+    /// Count an API request (retries included) in the latency histogram
+    pub fn record_latency(&mut self, elapsed_secs: i64) {
+        let bucket = match LATENCY_BUCKETS_SECS
+            .iter()
+            .find(|&&bound| elapsed_secs <= bound)
+        {
+            Some(bound) => format!("le_{}s", bound),
+            None => format!(
+                "gt_{}s",
+                LATENCY_BUCKETS_SECS.last().copied().unwrap_or_default()
+            ),
+        };
+        self.increment("latency", &bucket);
+    }
+
+    /// Record a finished scan (rows returned and duration from begin_scan to end_scan)
+    pub fn record_scan(&mut self, object: &str, rows: usize, duration_secs: i64) {
+        self.last_scan = Some(serde_json::json!({
+            "object": object,
+            "rows": rows,
+            "duration_secs": duration_secs,
+            "finished": time::epoch_secs(),
+        }));
+        self.increment("scans", object);
+    }
+
+    /// Merge the collected updates into the metadata document (one read-modify-write)
+    pub fn flush(&mut self) {
+        let pending = std::mem::take(self);
+        if pending.last_success.is_none()
+            && pending.last_failure.is_none()
+            && pending.last_scan.is_none()
+            && pending.counters.is_empty()
+        {
+            return;
+        }
+
+        let mut doc = load();
+        if let Some(at) = pending.last_success {
+            doc.insert("last_success".to_string(), at.into());
+        }
+        if let Some((at, error)) = pending.last_failure {
+            doc.insert("last_failure".to_string(), at.into());
+            doc.insert("last_error".to_string(), error.into());
+        }
+        if let Some(scan) = pending.last_scan {
+            doc.insert("last_scan".to_string(), scan);
+        }
+        for ((group, key), n) in pending.counters {
+            update_group(&mut doc, group, |counters| add(counters, &key, n));
+        }
+        store(doc);
+    }
+}
@@ -0,0 +1,131 @@
+// Buffered modify layer shared by write-capable objects
+//
+// Rows are validated and converted to JSON payloads in `insert`, buffered, and
+// flushed as one POST per batch once `batch_size` rows or `max_batch_bytes`
+// bytes are reached (and once more in `end_modify`). Rows rejected by validation
+// or by the API are collected with their position in the statement so a single
+// error can report every rejected row, plus how many rows were submitted.
+
+use serde_json::Value as JsonValue;
+
+/// Rows of one flushed batch, with their 1-based row numbers in the statement
+#[derive(Debug, Default)]
+pub struct Batch {
+    pub row_numbers: Vec<usize>,
+    pub payloads: Vec<JsonValue>,
+}
+
+impl Batch {
+    pub fn len(&self) -> usize {
+        self.payloads.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.payloads.is_empty()
+    }
+
+    /// Human readable row range (e.g. "rows 501-1000")
+    pub fn describe_rows(&self) -> String {
+        match (self.row_numbers.first(), self.row_numbers.last()) {
+            (Some(first), Some(last)) if first != last => format!("rows {}-{}", first, last),
+            (Some(first), _) => format!("row {}", first),
+            _ => "no rows".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ModifyBuffer {
+    pub batch_size: usize,
+    pub max_batch_bytes: usize,
+    pending: Batch,
+    pending_bytes: usize,
+    row_count: usize,
+    submitted: usize,
+    rejected: Vec<String>,
+}
+
+impl ModifyBuffer {
+    /// Create a buffer from the `batch_size` and `max_batch_bytes` options
+    pub fn new(batch_size: usize, max_batch_bytes: usize) -> Self {
+        Self {
+            batch_size,
+            max_batch_bytes,
+            ..Self::default()
+        }
+    }
+
+    /// Number of rows seen so far
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Number of rows accepted by the API so far
+    pub fn submitted(&self) -> usize {
+        self.submitted
+    }
+
+    /// Add a converted row (or its validation error)
+    ///
+    /// Returns a batch to flush first when adding the row would exceed
+    /// `max_batch_bytes`, or the full batch when `batch_size` is reached.
+    pub fn push(&mut self, row: Result<JsonValue, String>) -> Option<Batch> {
+        self.row_count += 1;
+        let row_number = self.row_count;
+
+        let payload = match row {
+            Ok(payload) => payload,
+            Err(reason) => {
+                self.reject(row_number, &reason);
+                return None;
+            }
+        };
+
+        // +1 for the separating comma in the JSON array
+        let bytes = payload.to_string().len() + 1;
+        let mut ready = None;
+        if !self.pending.is_empty() && self.pending_bytes + bytes > self.max_batch_bytes {
+            ready = Some(self.take_batch());
+        }
+
+        self.pending.row_numbers.push(row_number);
+        self.pending.payloads.push(payload);
+        self.pending_bytes += bytes;
+
+        if ready.is_none() && self.pending.len() >= self.batch_size {
+            ready = Some(self.take_batch());
+        }
+        ready
+    }
+
+    /// Take the buffered rows (e.g. for the final flush in `end_modify`)
+    pub fn take_batch(&mut self) -> Batch {
+        self.pending_bytes = 0;
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Record a rejected row
+    pub fn reject(&mut self, row: impl std::fmt::Display, reason: &str) {
+        self.rejected.push(format!("row {}: {}", row, reason));
+    }
+
+    /// Record the outcome of a submitted batch of `rows` (`rejected` rows were not accepted)
+    pub fn record_submitted(&mut self, rows: usize, rejected: usize) {
+        self.submitted += rows.saturating_sub(rejected);
+    }
+
+    /// Summarize the statement: Err lists every rejected row
+    pub fn finish(&self, object: &str) -> Result<(), String> {
+        if self.rejected.is_empty() {
+            return Ok(());
+        }
+        Err(format!(
+            "{}: {} of {} rows rejected ({} submitted): {}",
+            object,
+            self.rejected.len(),
+            self.row_count,
+            self.submitted,
+            self.rejected.join("; ")
+        ))
+    }
+}
@@ -0,0 +1,232 @@
+// German postal code (Postleitzahl) helpers
+//
+// Embedded, compact lookup data so obviously invalid postal codes are rejected
+// before a request is sent to the Corrently API (and counted against the quota).
+
+/// Assigned German postal code ranges (inclusive)
+///
+/// Derived from the two-digit leading regions (Leitregionen): 00, 05, 43 and 62
+/// are not assigned, everything else between 01001 and 99998 is.
+const VALID_RANGES: &[(u32, u32)] = &[
+    (1_001, 4_999),
+    (6_001, 42_999),
+    (44_001, 61_999),
+    (63_001, 99_998),
+];
+
+/// Validate a German postal code (5 digits within an assigned range)
+pub fn validate(postal_code: &str) -> Result<(), String> {
+    if postal_code.len() != 5 || !postal_code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!(
+            "invalid postal code '{}': German postal codes have exactly 5 digits (e.g., '69168'). \
+             Set table option validate_postal_code 'off' to skip this check.",
+            postal_code
+        ));
+    }
+
+    let value: u32 = postal_code
+        .parse()
+        .map_err(|_| format!("invalid postal code '{}'", postal_code))?;
+
+    if !VALID_RANGES
+        .iter()
+        .any(|&(start, end)| (start..=end).contains(&value))
+    {
+        return Err(format!(
+            "invalid postal code '{}': not within an assigned German postal code range. \
+             Set table option validate_postal_code 'off' to skip this check.",
+            postal_code
+        ));
+    }
+
+    Ok(())
+}
+
+/// Region metadata for a two-digit postal code prefix (Leitregion)
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    /// Federal state (ISO 3166-2:DE subdivision code, e.g. "BW")
+    pub federal_state: &'static str,
+    /// Main city of the leading region
+    pub city: &'static str,
+}
+
+/// Leading regions keyed by the first two digits of the postal code
+///
+/// Leading regions can cross state borders; the state of the main city is used.
+const REGIONS: &[(u8, &str, &str)] = &[
+    (1, "SN", "Dresden"),
+    (2, "SN", "Bautzen"),
+    (3, "BB", "Cottbus"),
+    (4, "SN", "Leipzig"),
+    (6, "ST", "Halle (Saale)"),
+    (7, "TH", "Gera"),
+    (8, "SN", "Zwickau"),
+    (9, "SN", "Chemnitz"),
+    (10, "BE", "Berlin"),
+    (11, "BE", "Berlin"),
+    (12, "BE", "Berlin"),
+    (13, "BE", "Berlin"),
+    (14, "BB", "Potsdam"),
+    (15, "BB", "Frankfurt (Oder)"),
+    (16, "BB", "Oranienburg"),
+    (17, "MV", "Neubrandenburg"),
+    (18, "MV", "Rostock"),
+    (19, "MV", "Schwerin"),
+    (20, "HH", "Hamburg"),
+    (21, "NI", "Lüneburg"),
+    (22, "HH", "Hamburg"),
+    (23, "SH", "Lübeck"),
+    (24, "SH", "Kiel"),
+    (25, "SH", "Elmshorn"),
+    (26, "NI", "Oldenburg"),
+    (27, "NI", "Cuxhaven"),
+    (28, "HB", "Bremen"),
+    (29, "NI", "Celle"),
+    (30, "NI", "Hannover"),
+    (31, "NI", "Hildesheim"),
+    (32, "NW", "Herford"),
+    (33, "NW", "Bielefeld"),
+    (34, "HE", "Kassel"),
+    (35, "HE", "Gießen"),
+    (36, "HE", "Fulda"),
+    (37, "NI", "Göttingen"),
+    (38, "NI", "Braunschweig"),
+    (39, "ST", "Magdeburg"),
+    (40, "NW", "Düsseldorf"),
+    (41, "NW", "Mönchengladbach"),
+    (42, "NW", "Wuppertal"),
+    (44, "NW", "Dortmund"),
+    (45, "NW", "Essen"),
+    (46, "NW", "Oberhausen"),
+    (47, "NW", "Duisburg"),
+    (48, "NW", "Münster"),
+    (49, "NI", "Osnabrück"),
+    (50, "NW", "Köln"),
+    (51, "NW", "Bergisch Gladbach"),
+    (52, "NW", "Aachen"),
+    (53, "NW", "Bonn"),
+    (54, "RP", "Trier"),
+    (55, "RP", "Mainz"),
+    (56, "RP", "Koblenz"),
+    (57, "NW", "Siegen"),
+    (58, "NW", "Hagen"),
+    (59, "NW", "Hamm"),
+    (60, "HE", "Frankfurt am Main"),
+    (61, "HE", "Bad Homburg"),
+    (63, "HE", "Offenbach am Main"),
+    (64, "HE", "Darmstadt"),
+    (65, "HE", "Wiesbaden"),
+    (66, "SL", "Saarbrücken"),
+    (67, "RP", "Ludwigshafen am Rhein"),
+    (68, "BW", "Mannheim"),
+    (69, "BW", "Heidelberg"),
+    (70, "BW", "Stuttgart"),
+    (71, "BW", "Böblingen"),
+    (72, "BW", "Tübingen"),
+    (73, "BW", "Esslingen am Neckar"),
+    (74, "BW", "Heilbronn"),
+    (75, "BW", "Pforzheim"),
+    (76, "BW", "Karlsruhe"),
+    (77, "BW", "Offenburg"),
+    (78, "BW", "Villingen-Schwenningen"),
+    (79, "BW", "Freiburg im Breisgau"),
+    (80, "BY", "München"),
+    (81, "BY", "München"),
+    (82, "BY", "Fürstenfeldbruck"),
+    (83, "BY", "Rosenheim"),
+    (84, "BY", "Landshut"),
+    (85, "BY", "Ingolstadt"),
+    (86, "BY", "Augsburg"),
+    (87, "BY", "Kempten (Allgäu)"),
+    (88, "BW", "Ravensburg"),
+    (89, "BW", "Ulm"),
+    (90, "BY", "Nürnberg"),
+    (91, "BY", "Erlangen"),
+    (92, "BY", "Amberg"),
+    (93, "BY", "Regensburg"),
+    (94, "BY", "Passau"),
+    (95, "BY", "Bayreuth"),
+    (96, "BY", "Bamberg"),
+    (97, "BY", "Würzburg"),
+    (98, "TH", "Suhl"),
+    (99, "TH", "Erfurt"),
+];
+
+/// Representative postal codes (major cities) used to expand `WHERE federal_state = '..'`
+const STATE_REPRESENTATIVES: &[(&str, &[&str])] = &[
+    ("BW", &["70173", "76133", "68159", "79098"]),
+    ("BY", &["80331", "90402", "86150", "93047"]),
+    ("BE", &["10115"]),
+    ("BB", &["14467", "03046"]),
+    ("HB", &["28195"]),
+    ("HH", &["20095"]),
+    ("HE", &["60311", "65183", "34117"]),
+    ("MV", &["18055", "19053"]),
+    ("NI", &["30159", "38100", "26122"]),
+    ("NW", &["50667", "40213", "44135", "45127"]),
+    ("RP", &["55116", "67059"]),
+    ("SL", &["66111"]),
+    ("SN", &["01067", "04109", "09111"]),
+    ("ST", &["39104", "06108"]),
+    ("SH", &["24103", "23552"]),
+    ("TH", &["99084", "07743"]),
+];
+
+/// Look up the leading region of a postal code
+pub fn region(postal_code: &str) -> Option<Region> {
+    let prefix: u8 = postal_code.get(..2)?.parse().ok()?;
+    REGIONS
+        .iter()
+        .find(|&&(p, _, _)| p == prefix)
+        .map(|&(_, federal_state, city)| Region {
+            federal_state,
+            city,
+        })
+}
+
+/// Transmission system operator (TSO) control area of a federal state
+///
+/// Control areas do not follow state borders exactly; the dominant TSO is used.
+pub fn tso_zone(federal_state: &str) -> Option<&'static str> {
+    match federal_state {
+        "BE" | "BB" | "HH" | "MV" | "SN" | "ST" | "TH" => Some("50Hertz"),
+        "NW" | "RP" | "SL" => Some("Amprion"),
+        "SH" | "NI" | "HB" | "HE" | "BY" => Some("TenneT"),
+        "BW" => Some("TransnetBW"),
+        _ => None,
+    }
+}
+
+/// Representative postal codes for a federal state code
+///
+/// Matching is exact: the `federal_state` column returns the uppercase code and
+/// PostgreSQL re-checks the WHERE clause against it, so e.g. 'bw' would match no rows.
+pub fn representative_postal_codes(federal_state: &str) -> Result<&'static [&'static str], String> {
+    STATE_REPRESENTATIVES
+        .iter()
+        .find(|(state, _)| *state == federal_state)
+        .map(|&(_, codes)| codes)
+        .ok_or_else(|| {
+            let valid = STATE_REPRESENTATIVES
+                .iter()
+                .map(|(state, _)| *state)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let canonical = federal_state.trim().to_ascii_uppercase();
+            if STATE_REPRESENTATIVES
+                .iter()
+                .any(|(state, _)| *state == canonical)
+            {
+                format!(
+                    "unknown federal_state '{}', did you mean '{}'? (codes are case-sensitive: {})",
+                    federal_state, canonical, valid
+                )
+            } else {
+                format!(
+                    "unknown federal_state '{}' (expected one of: {})",
+                    federal_state, valid
+                )
+            }
+        })
+}
@@ -0,0 +1,51 @@
+// Request rate limiter shared by all requests of a scan
+//
+// Protects the daily API quota when one scan fans out into many requests
+// (postal code lists, federal state expansion, history chunks):
+// - `request_interval_ms` pauses between consecutive API requests
+// - `max_requests_per_scan` fails the scan before exceeding the request budget
+
+use crate::bindings::supabase::wrappers::time;
+use crate::error::{CorrentlyError, CorrentlyResult};
+
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    /// Pause between consecutive requests (0 disables)
+    pub interval_ms: u64,
+    /// Maximum requests per scan (None = unlimited)
+    pub max_requests: Option<u32>,
+    requests: u32,
+}
+
+impl RateLimiter {
+    pub fn new(interval_ms: u64, max_requests: Option<u32>) -> Self {
+        Self {
+            interval_ms,
+            max_requests,
+            requests: 0,
+        }
+    }
+
+    /// Number of requests sent in the current scan
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    /// Wait for the next request slot, or fail when the request budget is used up
+    pub fn acquire(&mut self) -> CorrentlyResult {
+        if let Some(max) = self.max_requests {
+            if self.requests >= max {
+                return Err(CorrentlyError::RateLimited(format!(
+                    "request limit reached: this scan needs more than {} API requests \
+                     (raise max_requests_per_scan or narrow the query)",
+                    max
+                )));
+            }
+        }
+        if self.requests > 0 && self.interval_ms > 0 {
+            time::sleep(self.interval_ms);
+        }
+        self.requests += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,402 @@
+// Forecast resampling helpers
+//
+// The Corrently API returns hourly forecast periods. These helpers convert the
+// flattened per-column vectors to a different bucket size:
+// - Upsampling (e.g. hour → 15min) splits every period into equal sub-periods,
+//   either repeating the value (step) or interpolating towards the next period (linear)
+// - Downsampling (e.g. hour → day) groups periods into UTC-aligned buckets and
+//   reduces every metric with mean, min or max; partial buckets at the edges
+//   cover only the source periods they contain
+
+const MS_PER_MINUTE: i64 = 60_000;
+
+/// Target bucket size parsed from the `resolution` qual / table option
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    pub minutes: i64,
+    pub label: String,
+}
+
+impl Resolution {
+    /// Parse a resolution string
+    ///
+    /// Accepted values: `15min`, `hour`, `day` and custom sizes that divide an
+    /// hour or are whole hours, such as `30min`, `2hour`, `6h` or `2day`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_ascii_lowercase();
+        let digits_end = normalized
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(normalized.len());
+        let (count_str, unit) = normalized.split_at(digits_end);
+
+        let count: i64 = if count_str.is_empty() {
+            1
+        } else {
+            count_str
+                .parse()
+                .map_err(|_| format!("invalid resolution '{}'", value))?
+        };
+
+        let unit_minutes = match unit {
+            "min" | "m" | "minute" | "minutes" => 1,
+            "hour" | "hours" | "h" => 60,
+            "day" | "days" | "d" => 1440,
+            _ => {
+                return Err(format!(
+                    "invalid resolution '{}' (expected e.g. '15min', 'hour', 'day')",
+                    value
+                ))
+            }
+        };
+
+        let minutes = count.checked_mul(unit_minutes).unwrap_or(0);
+        if minutes <= 0 || (60 % minutes != 0 && minutes % 60 != 0) {
+            return Err(format!(
+                "invalid resolution '{}' (must divide an hour, e.g. '15min', or be a whole number of hours, e.g. '6h')",
+                value
+            ));
+        }
+
+        Ok(Self {
+            minutes,
+            label: value.trim().to_string(),
+        })
+    }
+
+    pub fn millis(&self) -> i64 {
+        self.minutes * MS_PER_MINUTE
+    }
+}
+
+/// How values are filled in when upsampling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    #[default]
+    Step,
+    Linear,
+}
+
+impl Interpolation {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "step" => Ok(Self::Step),
+            "linear" => Ok(Self::Linear),
+            _ => Err(format!(
+                "invalid interpolation '{}' (expected 'step' or 'linear')",
+                value
+            )),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Step => "step",
+            Self::Linear => "linear",
+        }
+    }
+}
+
+/// How values are reduced when downsampling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aggregation {
+    #[default]
+    Mean,
+    Min,
+    Max,
+}
+
+impl Aggregation {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "mean" | "avg" => Ok(Self::Mean),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            _ => Err(format!(
+                "invalid aggregation '{}' (expected 'mean', 'min' or 'max')",
+                value
+            )),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+
+    fn reduce(self, values: &[f64]) -> f64 {
+        match self {
+            Self::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Self::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Self::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+/// Resampling plan computed from the source period boundaries
+///
+/// Every output row is described by its period start/end and a source mapping:
+/// - `Split`: sub-period `part` of `parts` of source row `index`, interpolating
+///   towards row `next` when the following period is contiguous
+/// - `Group`: contiguous source rows `start..end`
+#[derive(Debug, Clone)]
+pub enum Bucket {
+    Split {
+        index: usize,
+        next: Option<usize>,
+        part: usize,
+        parts: usize,
+    },
+    Group {
+        start: usize,
+        end: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub period_start: Vec<i64>,
+    pub period_end: Vec<i64>,
+    pub buckets: Vec<Bucket>,
+    pub interpolation: Interpolation,
+    pub aggregation: Aggregation,
+}
+
+impl Plan {
+    /// Build a resampling plan, or `None` when the data is already at the target resolution
+    pub fn build(
+        period_start: &[i64],
+        period_end: &[i64],
+        resolution: &Resolution,
+        interpolation: Interpolation,
+        aggregation: Aggregation,
+    ) -> Option<Self> {
+        let target = resolution.millis();
+        let source = match (period_start.first(), period_end.first()) {
+            (Some(&start), Some(&end)) if end > start => end - start,
+            _ => return None,
+        };
+
+        let mut plan = Self {
+            period_start: Vec::new(),
+            period_end: Vec::new(),
+            buckets: Vec::new(),
+            interpolation,
+            aggregation,
+        };
+
+        if target == source {
+            return None;
+        } else if target < source {
+            // Upsample: split each source period into equal sub-periods
+            for (index, (&start, &end)) in period_start.iter().zip(period_end).enumerate() {
+                let parts = ((end - start) / target).max(1) as usize;
+                let next = Some(index + 1).filter(|&n| period_start.get(n) == Some(&end));
+                for part in 0..parts {
+                    let sub_start = start + part as i64 * target;
+                    plan.period_start.push(sub_start);
+                    plan.period_end.push((sub_start + target).min(end));
+                    plan.buckets.push(Bucket::Split {
+                        index,
+                        next,
+                        part,
+                        parts,
+                    });
+                }
+            }
+        } else {
+            // Downsample: group source periods by UTC-aligned bucket
+            // (a period starting before its predecessor begins a new series, e.g. another postal code)
+            // Partial buckets at the edges of the data are clamped to the periods they contain
+            let mut start = 0;
+            while start < period_start.len() {
+                let bucket_start = period_start[start].div_euclid(target) * target;
+                let mut end = start + 1;
+                while end < period_start.len()
+                    && period_start[end] > period_start[end - 1]
+                    && period_start[end].div_euclid(target) * target == bucket_start
+                {
+                    end += 1;
+                }
+                plan.period_start
+                    .push(bucket_start.max(period_start[start]));
+                plan.period_end
+                    .push((bucket_start + target).min(period_end[end - 1]));
+                plan.buckets.push(Bucket::Group { start, end });
+                start = end;
+            }
+        }
+
+        Some(plan)
+    }
+
+    /// Resample a numeric column
+    pub fn apply_f64(&self, values: &[f64]) -> Vec<f64> {
+        self.buckets
+            .iter()
+            .map(|bucket| match *bucket {
+                Bucket::Split {
+                    index,
+                    next,
+                    part,
+                    parts,
+                } => {
+                    let current = values[index];
+                    match (self.interpolation, next.map(|n| values[n])) {
+                        (Interpolation::Linear, Some(next)) => {
+                            current + (next - current) * part as f64 / parts as f64
+                        }
+                        _ => current,
+                    }
+                }
+                Bucket::Group { start, end } => self.aggregation.reduce(&values[start..end]),
+            })
+            .collect()
+    }
+
+    /// Resample an integer column (interpolated / averaged values are rounded)
+    pub fn apply_i64(&self, values: &[i64]) -> Vec<i64> {
+        let as_f64: Vec<f64> = values.iter().map(|&v| v as f64).collect();
+        self.apply_f64(&as_f64)
+            .into_iter()
+            .map(|v| v.round() as i64)
+            .collect()
+    }
+
+    /// Resample a column by picking one representative source row per bucket
+    ///
+    /// `last` selects the final row of a group instead of the first
+    pub fn pick<T: Clone>(&self, values: &[T], last: bool) -> Vec<T> {
+        self.buckets
+            .iter()
+            .map(|bucket| match *bucket {
+                Bucket::Split { index, .. } => values[index].clone(),
+                Bucket::Group { start, end } => values[if last { end - 1 } else { start }].clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * MS_PER_MINUTE;
+
+    /// Hourly periods starting at the given hours (relative to the epoch)
+    fn hourly(start_hours: &[i64]) -> (Vec<i64>, Vec<i64>) {
+        let start: Vec<i64> = start_hours.iter().map(|h| h * HOUR).collect();
+        let end = start.iter().map(|s| s + HOUR).collect();
+        (start, end)
+    }
+
+    fn hourly_plan(start_hours: &[i64], resolution: &str, interpolation: Interpolation) -> Plan {
+        let (start, end) = hourly(start_hours);
+        Plan::build(
+            &start,
+            &end,
+            &Resolution::parse(resolution).unwrap(),
+            interpolation,
+            Aggregation::Mean,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_resolutions() {
+        assert_eq!(Resolution::parse("15min").unwrap().minutes, 15);
+        assert_eq!(Resolution::parse("hour").unwrap().minutes, 60);
+        assert_eq!(Resolution::parse(" 6H ").unwrap().minutes, 360);
+        assert_eq!(Resolution::parse("2day").unwrap().minutes, 2880);
+        assert!(Resolution::parse("7min").is_err());
+        assert!(Resolution::parse("90min").is_err());
+        assert!(Resolution::parse("0h").is_err());
+        assert!(Resolution::parse("week").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_overflowing_resolution() {
+        assert!(Resolution::parse("999999999999999999d").is_err());
+        assert!(Resolution::parse("9999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn same_resolution_needs_no_plan() {
+        let (start, end) = hourly(&[0, 1]);
+        let hour = Resolution::parse("hour").unwrap();
+        assert!(Plan::build(&start, &end, &hour, Interpolation::Step, Aggregation::Mean).is_none());
+    }
+
+    #[test]
+    fn upsample_step_repeats_values() {
+        let plan = hourly_plan(&[0, 1], "15min", Interpolation::Step);
+        assert_eq!(plan.period_start.len(), 8);
+        assert_eq!(plan.period_start[1], 15 * MS_PER_MINUTE);
+        assert_eq!(plan.period_end[7], 2 * HOUR);
+        assert_eq!(
+            plan.apply_f64(&[10.0, 20.0]),
+            vec![10.0, 10.0, 10.0, 10.0, 20.0, 20.0, 20.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn upsample_linear_interpolates_towards_next_period() {
+        let plan = hourly_plan(&[0, 1], "15min", Interpolation::Linear);
+        // The last period has no successor and is repeated
+        assert_eq!(
+            plan.apply_f64(&[10.0, 20.0]),
+            vec![10.0, 12.5, 15.0, 17.5, 20.0, 20.0, 20.0, 20.0]
+        );
+        assert_eq!(plan.apply_i64(&[10, 20])[1], 13);
+    }
+
+    #[test]
+    fn upsample_does_not_interpolate_across_gaps() {
+        let plan = hourly_plan(&[0, 2], "30min", Interpolation::Linear);
+        assert_eq!(plan.apply_f64(&[10.0, 20.0]), vec![10.0, 10.0, 20.0, 20.0]);
+    }
+
+    #[test]
+    fn downsample_clamps_partial_buckets() {
+        // 22:00-02:00 across midnight: two partial days
+        let plan = hourly_plan(&[22, 23, 24, 25], "day", Interpolation::Step);
+        assert_eq!(plan.period_start, vec![22 * HOUR, 24 * HOUR]);
+        assert_eq!(plan.period_end, vec![24 * HOUR, 26 * HOUR]);
+        assert_eq!(plan.apply_f64(&[1.0, 3.0, 5.0, 9.0]), vec![2.0, 7.0]);
+    }
+
+    #[test]
+    fn downsample_aggregations() {
+        let (start, end) = hourly(&[0, 1, 2]);
+        let day = Resolution::parse("day").unwrap();
+        let build = |aggregation| {
+            Plan::build(&start, &end, &day, Interpolation::Step, aggregation).unwrap()
+        };
+        let values = [4.0, 1.0, 7.0];
+        assert_eq!(build(Aggregation::Mean).apply_f64(&values), vec![4.0]);
+        assert_eq!(build(Aggregation::Min).apply_f64(&values), vec![1.0]);
+        assert_eq!(build(Aggregation::Max).apply_f64(&values), vec![7.0]);
+    }
+
+    #[test]
+    fn downsample_breaks_series_between_sites() {
+        // Two postal codes with the same hours, concatenated
+        let plan = hourly_plan(&[0, 1, 0, 1], "day", Interpolation::Step);
+        assert_eq!(plan.period_start, vec![0, 0]);
+        assert_eq!(plan.period_end, vec![2 * HOUR, 2 * HOUR]);
+        assert_eq!(plan.apply_f64(&[1.0, 3.0, 10.0, 20.0]), vec![2.0, 15.0]);
+        assert_eq!(plan.pick(&["a", "a", "b", "b"], false), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn pick_first_or_last_row() {
+        let plan = hourly_plan(&[0, 1, 2], "day", Interpolation::Step);
+        assert_eq!(plan.pick(&[100, 200, 300], false), vec![100]);
+        assert_eq!(plan.pick(&[100, 200, 300], true), vec![300]);
+
+        let plan = hourly_plan(&[0], "30min", Interpolation::Step);
+        assert_eq!(plan.pick(&[100], true), vec![100, 100]);
+    }
+}
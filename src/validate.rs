@@ -0,0 +1,240 @@
+// Server and table option validation
+//
+// Checks every known option on both levels (URL syntax, numeric ranges, enum
+// values, JSON headers, postal codes) and reports all problems in one error,
+// with suggestions for misspelled values.
+//
+// The Wrappers host does not call into the WASM module on CREATE / ALTER and
+// does not expose the list of option keys, so validation runs in `init` (first
+// query) and unknown option names cannot be detected.
+
+use crate::bindings::supabase::wrappers::types::{Context, Options, OptionsType};
+use crate::endpoints::{Endpoint, Route};
+use crate::log::LogLevel;
+use crate::resample::{Aggregation, Interpolation, Resolution};
+use crate::{config, headers, plz, CorrentlyFdw, OBJECTS};
+
+/// Maximum forecast horizon of the prediction endpoint (`hours` option)
+const MAX_FORECAST_HOURS: i64 = 113;
+
+/// Validate all server and table options, reporting every problem at once
+pub fn validate_options(ctx: &Context) -> Result<(), String> {
+    let server = ctx.get_options(&OptionsType::Server);
+    let table = ctx.get_options(&OptionsType::Table);
+    let mut problems = Vec::new();
+
+    // API key (server or table)
+    let has_key = [&server, &table]
+        .iter()
+        .any(|opts| opts.get("api_key_id").is_some() || opts.get("api_key").is_some());
+    if !has_key {
+        problems.push(
+            "missing API key: set 'api_key_id' (Vault secret ID) in server or table options"
+                .to_string(),
+        );
+    }
+
+    for (level, opts) in [("server", &server), ("table", &table)] {
+        check_level(level, opts, &mut problems);
+    }
+    check_table(ctx, &table, &mut problems);
+
+    match problems.len() {
+        0 => Ok(()),
+        n => Err(format!(
+            "invalid Corrently FDW options ({} problem{}):\n  - {}",
+            n,
+            if n == 1 { "" } else { "s" },
+            problems.join("\n  - ")
+        )),
+    }
+}
+
+/// Options that can be set on either level
+fn check_level(level: &str, opts: &Options, problems: &mut Vec<String>) {
+    if let Some(url) = opts.get("api_url") {
+        if let Err(reason) = check_url(&url) {
+            problems.push(format!("{} option api_url '{}': {}", level, url, reason));
+        }
+    }
+
+    for numeric in config::NUMERIC_OPTIONS {
+        if let Some(value) = opts.get(numeric.key) {
+            if let Err(reason) = numeric.parse(&value) {
+                problems.push(format!("{} option: {}", level, reason));
+            }
+        }
+    }
+
+    for key in ["headers", "secret_headers"] {
+        if let Some(value) = opts.get(key) {
+            if let Err(reason) = headers::parse(key, &value) {
+                problems.push(format!("{} option {}: {}", level, key, reason));
+            }
+        }
+    }
+
+    if let Some(value) = opts.get("validate_postal_code") {
+        if !is_bool_like(&value) {
+            problems.push(format!(
+                "{} option validate_postal_code '{}': expected 'on' or 'off'",
+                level, value
+            ));
+        }
+    }
+
+    if let Some(value) = opts.get("log_level") {
+        if let Err(reason) = LogLevel::parse(&value) {
+            problems.push(format!("{} option: {}", level, reason));
+        }
+    }
+
+    // endpoint_path replaces the whole request path, so it only makes sense for one object
+    let endpoint_path = match level {
+        "table" => opts.get("endpoint_path"),
+        _ => {
+            if opts.get("endpoint_path").is_some() {
+                problems.push(format!(
+                    "{} option endpoint_path: only supported as a table option (it replaces the request path of every object)",
+                    level
+                ));
+            }
+            None
+        }
+    };
+    if opts.get("api_version").is_some() || endpoint_path.is_some() {
+        if let Err(reason) = Route::resolve(
+            Endpoint::Prediction,
+            opts.get("api_version").as_deref(),
+            endpoint_path.as_deref(),
+        ) {
+            problems.push(format!("{} option: {}", level, reason));
+        }
+    }
+}
+
+/// Table-only options
+fn check_table(ctx: &Context, table: &Options, problems: &mut Vec<String>) {
+    let object = table
+        .get("object")
+        .unwrap_or_else(|| "gsi_prediction".to_string());
+    if !OBJECTS.contains(&object.as_str()) {
+        problems.push(match suggest(&object, OBJECTS) {
+            Some(candidate) => format!(
+                "table option object '{}': unknown object, did you mean '{}'?",
+                object, candidate
+            ),
+            None => format!(
+                "table option object '{}': unknown object (expected one of: {})",
+                object,
+                OBJECTS.join(", ")
+            ),
+        });
+    }
+
+    if let Some(hours) = table.get("hours") {
+        if let Err(reason) = check_range(&hours, 1, MAX_FORECAST_HOURS) {
+            problems.push(format!("table option hours '{}': {}", hours, reason));
+        }
+    }
+
+    // Postal codes are checked like query values (unless validate_postal_code is off)
+    let check_postal_codes =
+        !config::option(ctx, "validate_postal_code").is_some_and(|v| config::is_disabled(&v));
+    let mut postal_codes = Vec::new();
+    if let Some(postal_code) = table.get("postal_code") {
+        postal_codes.push(postal_code.trim().to_string());
+    }
+    if let Some(list) = table.get("postal_codes") {
+        match CorrentlyFdw::parse_site_list(&list, table.get("site_labels")) {
+            Ok(sites) => postal_codes.extend(sites.into_iter().map(|(pc, _)| pc)),
+            Err(reason) => problems.push(format!("table option postal_codes: {}", reason)),
+        }
+    }
+    if check_postal_codes {
+        for postal_code in postal_codes {
+            if let Err(reason) = plz::validate(&postal_code) {
+                problems.push(format!("table option postal_code: {}", reason));
+            }
+        }
+    }
+
+    if let Some(value) = table.get("resolution") {
+        if let Err(reason) = Resolution::parse(&value) {
+            problems.push(format!("table option resolution: {}", reason));
+        }
+    }
+    if let Some(value) = table.get("interpolation") {
+        if let Err(reason) = Interpolation::parse(&value) {
+            problems.push(format!("table option interpolation: {}", reason));
+        }
+    }
+    if let Some(value) = table.get("aggregation") {
+        if let Err(reason) = Aggregation::parse(&value) {
+            problems.push(format!("table option aggregation: {}", reason));
+        }
+    }
+}
+
+/// Check an http(s) URL with a host and without query string or whitespace
+fn check_url(url: &str) -> Result<(), String> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or("must start with https:// or http://")?;
+    let host = rest.split('/').next().unwrap_or_default();
+    if host.is_empty() {
+        return Err("missing host name".to_string());
+    }
+    if url.chars().any(char::is_whitespace) {
+        return Err("must not contain whitespace".to_string());
+    }
+    if url.contains(['?', '#']) {
+        return Err("must not contain a query string or fragment".to_string());
+    }
+    Ok(())
+}
+
+/// Check an integer option against an inclusive range
+fn check_range(value: &str, min: i64, max: i64) -> Result<(), String> {
+    match value.trim().parse::<i64>() {
+        Ok(n) if (min..=max).contains(&n) => Ok(()),
+        Ok(_) => Err(format!("must be between {} and {}", min, max)),
+        Err(_) => Err(format!("expected an integer between {} and {}", min, max)),
+    }
+}
+
+fn is_bool_like(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "on" | "off" | "true" | "false" | "1" | "0" | "yes" | "no"
+    )
+}
+
+/// Closest candidate within a small edit distance (for "did you mean" hints)
+pub fn suggest<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let value = value.trim().to_ascii_lowercase();
+    candidates
+        .iter()
+        .map(|&candidate| (edit_distance(&value, candidate), candidate))
+        .filter(|&(distance, candidate)| distance <= (candidate.len() / 4).max(2))
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -185,7 +185,7 @@ LIMIT 10;
 \echo '\n=== Test 9: Missing postal_code parameter (should fail) ==='
 -- This should return an error
 SELECT * FROM fdw_corrently.gsi_prediction LIMIT 1;
--- Expected error: "postal_code parameter is required in WHERE clause"
+-- Expected error: "CORRENTLY-E007 validation: postal_code parameter is required in WHERE clause"
 
 -- ============================================
 -- PERFORMANCE & AGGREGATION
@@ -224,6 +224,401 @@ ORDER BY forecast_start_time
 LIMIT 48;
 -- Expected: ~48 rows, chronologically ordered, using native TIMESTAMP operations
 
+\echo '\n=== Test 13: Resampling to 15-minute slots and daily buckets ==='
+-- Requires: ALTER FOREIGN TABLE fdw_corrently.gsi_prediction
+--             ADD COLUMN resolution text, ADD COLUMN interpolation text, ADD COLUMN aggregation text;
+SELECT forecast_period_start, forecast_period_end, green_energy_index
+FROM fdw_corrently.gsi_prediction
+WHERE postal_code = '69168' AND resolution = '15min' AND interpolation = 'linear'
+LIMIT 8;
+-- Expected: 8 rows, 15-minute periods, green_energy_index moving linearly between hours
+
+SELECT forecast_period_start, forecast_period_end, green_energy_index, energy_price_eur_kwh
+FROM fdw_corrently.gsi_prediction
+WHERE postal_code = '69168' AND resolution = 'day' AND aggregation = 'max';
+-- Expected: ~5-6 rows, daily maxima; full days run 00:00-00:00 UTC, the first and last day
+--           are clamped to the forecast hours they contain
+
+SELECT * FROM fdw_corrently.gsi_prediction
+WHERE postal_code = '69168' AND resolution = '45min';
+-- Expected: ERROR: CORRENTLY-E007 validation: invalid resolution '45min' (must divide an hour, ...)
+
+\echo '\n=== Test 14: CO2 savings calculator (co2_savings object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.co2_savings (
+    postal_code text,
+    energy_kwh numeric,
+    flexibility_hours bigint,
+    duration_hours bigint,
+    baseline_co2_g numeric,
+    optimal_co2_g numeric,
+    savings_co2_g numeric,
+    savings_pct numeric,
+    green_mix_co2_g numeric,
+    average_co2_g numeric,
+    baseline_start_time timestamp with time zone,
+    optimal_start_time timestamp with time zone,
+    chosen_hours jsonb
+)
+SERVER corrently_server
+OPTIONS (object 'co2_savings');
+
+SELECT baseline_co2_g, optimal_co2_g, savings_co2_g, savings_pct, optimal_start_time, chosen_hours
+FROM fdw_corrently.co2_savings
+WHERE postal_code = '69168' AND energy_kwh = 50 AND flexibility_hours = 24 AND duration_hours = 4;
+-- Expected: 1 row, savings_co2_g >= 0, chosen_hours contains 4 consecutive hours within the next 24 hours
+
+\echo '\n=== Test 15: Threshold events (gsi_events object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_events (
+    postal_code text,
+    event_type text,
+    event_start timestamp with time zone,
+    event_end timestamp with time zone,
+    duration_hours numeric,
+    peak_value numeric,
+    peak_time timestamp with time zone,
+    threshold numeric,
+    green_threshold numeric,
+    price_threshold numeric
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_events');
+
+SELECT event_type, event_start, event_end, duration_hours, peak_value
+FROM fdw_corrently.gsi_events
+WHERE postal_code = '69168' AND green_threshold = 60;
+-- Expected: one row per island of hours with green_energy_index >= 60 or energy_price_eur_kwh < 0,
+--           ordered by event_start, duration_hours >= 1
+
+\echo '\n=== Test 16: Invalid postal codes (should fail before calling the API) ==='
+SELECT * FROM fdw_corrently.gsi_prediction WHERE postal_code = '6916' LIMIT 1;
+-- Expected error: "CORRENTLY-E007 validation: invalid postal code '6916': German postal codes have exactly 5 digits"
+SELECT * FROM fdw_corrently.gsi_prediction WHERE postal_code = '691680' LIMIT 1;
+-- Expected error: "invalid postal code '691680': German postal codes have exactly 5 digits"
+SELECT * FROM fdw_corrently.gsi_prediction WHERE postal_code = '62123' LIMIT 1;
+-- Expected error: "not within an assigned German postal code range"
+
+\echo '\n=== Test 17: Region lookup columns and federal_state expansion ==='
+-- Requires: ALTER FOREIGN TABLE fdw_corrently.gsi_prediction
+--             ADD COLUMN federal_state text, ADD COLUMN city text, ADD COLUMN tso_zone text;
+SELECT DISTINCT postal_code, federal_state, city, tso_zone
+FROM fdw_corrently.gsi_prediction
+WHERE postal_code = '69168';
+-- Expected: 1 row ('69168', 'BW', 'Heidelberg', 'TransnetBW')
+
+SELECT postal_code, COUNT(*) AS hours, ROUND(AVG(green_energy_index), 1) AS avg_green_index
+FROM fdw_corrently.gsi_prediction
+WHERE federal_state = 'BW'
+GROUP BY postal_code;
+-- Expected: 4 rows (70173, 76133, 68159, 79098), ~113 hours each
+
+SELECT COUNT(*) FROM fdw_corrently.gsi_prediction WHERE federal_state = 'bw';
+-- Expected: ERROR: CORRENTLY-E007 validation: unknown federal_state 'bw', did you mean 'BW'? (codes are case-sensitive: ...)
+
+\echo '\n=== Test 18: Regional generation mix (gsi_dispatch object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_dispatch (
+    postal_code text,
+    flow_type text,
+    origin text,
+    energy_kwh numeric,
+    share_pct numeric,
+    period_start timestamp with time zone,
+    period_end timestamp with time zone
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_dispatch');
+
+SELECT flow_type, origin, energy_kwh, share_pct, period_start
+FROM fdw_corrently.gsi_dispatch
+WHERE postal_code = '69168'
+ORDER BY flow_type, share_pct DESC;
+-- Expected: 'source' rows (shares per generation type) and 'import' rows (shares summing to ~100)
+
+\echo '\n=== Test 19: Scope 2 emissions (co2_footprint object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.co2_footprint (
+    postal_code text,
+    energy_kwh numeric,
+    mix text,
+    co2_g numeric,
+    co2_g_kwh numeric,
+    period_start timestamp with time zone,
+    period_end timestamp with time zone
+)
+SERVER corrently_server
+OPTIONS (object 'co2_footprint');
+
+SELECT mix, co2_g, co2_g_kwh, period_start, period_end
+FROM fdw_corrently.co2_footprint
+WHERE postal_code = '69168'
+  AND energy_kwh = 1500
+  AND period_start >= '2025-10-01 00:00:00+00'
+  AND period_end <= '2025-10-31 23:59:59+00';
+-- Expected: one row per mix ('standard', 'green'), co2_g > 0
+
+\echo '\n=== Test 20: Historic data (gsi_history object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_history (
+    forecast_start_time timestamp with time zone,
+    forecast_period_start timestamp with time zone,
+    forecast_period_end timestamp with time zone,
+    green_energy_index numeric,
+    renewable_energy_pct bigint,
+    wind_energy_pct bigint,
+    solar_energy_pct bigint,
+    net_wind_energy_pct bigint,
+    net_solar_energy_pct bigint,
+    smart_city_index bigint,
+    energy_price_eur_kwh numeric,
+    co2_baseline_g_kwh numeric,
+    standard_mix_co2_g_kwh bigint,
+    green_mix_co2_g_kwh bigint,
+    postal_code text,
+    forecast_created_at timestamp with time zone
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_history');
+
+SELECT COUNT(*) AS hours, MIN(forecast_start_time), MAX(forecast_start_time)
+FROM fdw_corrently.gsi_history
+WHERE postal_code = '69168'
+  AND forecast_start_time >= NOW() - INTERVAL '3 days'
+  AND forecast_start_time < NOW();
+-- Expected: ~72 rows fetched in 3 chunks (3 API requests)
+
+\echo '\n=== Test 21: Dynamic tariff price components (tariff_prices object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.tariff_prices (
+    postal_code text,
+    period_start timestamp with time zone,
+    period_end timestamp with time zone,
+    exchange_price_eur_kwh numeric,
+    grid_fee_eur_kwh numeric,
+    levies_eur_kwh numeric,
+    vat_eur_kwh numeric,
+    total_price_eur_kwh numeric
+)
+SERVER corrently_server
+OPTIONS (object 'tariff_prices');
+
+SELECT period_start, exchange_price_eur_kwh, grid_fee_eur_kwh, levies_eur_kwh, vat_eur_kwh, total_price_eur_kwh
+FROM fdw_corrently.tariff_prices
+WHERE postal_code = '69168'
+  AND period_start >= NOW()
+  AND period_start < NOW() + INTERVAL '24 hours'
+ORDER BY period_start;
+-- Expected: hourly rows, total_price_eur_kwh ≈ sum of components
+
+\echo '\n=== Test 22: Usage recommendations (gsi_advisor object) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_advisor (
+    postal_code text,
+    window_start timestamp with time zone,
+    window_end timestamp with time zone,
+    recommendation text,
+    reason text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_advisor');
+
+SELECT window_start, window_end, recommendation, reason
+FROM fdw_corrently.gsi_advisor
+WHERE postal_code = '69168'
+ORDER BY window_start
+LIMIT 12;
+-- Expected: one recommendation per window, text as returned by Corrently
+
+\echo '\n=== Test 23: Submit meter readings (meter_readings object, INSERT) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.meter_readings (
+    meter_id text,
+    reading_time timestamp with time zone,
+    reading_kwh numeric
+)
+SERVER corrently_server
+OPTIONS (object 'meter_readings', rowid_column 'meter_id');
+
+INSERT INTO fdw_corrently.meter_readings (meter_id, reading_time, reading_kwh)
+VALUES ('meter-001', NOW() - INTERVAL '1 hour', 1234.5),
+       ('meter-001', NOW(), 1235.1);
+-- Expected: INSERT 0 2
+
+INSERT INTO fdw_corrently.meter_readings (meter_id, reading_time, reading_kwh)
+VALUES ('meter-001', NOW(), 1235.1),
+       ('', NOW(), -1);
+-- Expected: ERROR: CORRENTLY-E007 validation: meter_readings: 1 of 2 rows rejected (1 submitted): row 2: meter_id is required
+
+ALTER FOREIGN TABLE fdw_corrently.meter_readings OPTIONS (ADD batch_size '100');
+INSERT INTO fdw_corrently.meter_readings (meter_id, reading_time, reading_kwh)
+SELECT 'meter-002', NOW() - (n || ' hours')::interval, 1000 + n
+FROM generate_series(1, 250) AS n;
+-- Expected: INSERT 0 250 (3 requests: 100 + 100 + 50 rows)
+
+\echo '\n=== Test 24: Endpoint path override (api_version / endpoint_path) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_prediction_versioned (
+    forecast_start_time timestamp with time zone,
+    green_energy_index numeric,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', api_version '2.0', endpoint_path '/v2.0/gsi/prediction');
+
+SELECT COUNT(*) AS forecast_hours
+FROM fdw_corrently.gsi_prediction_versioned
+WHERE postal_code = '69168';
+-- Expected: same row count as Test 1 (explicit path equals the default)
+
+\echo '\n=== Test 25: Custom headers (headers / user_agent table options) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_prediction_tenant (
+    forecast_start_time timestamp with time zone,
+    green_energy_index numeric,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', headers '{"x-tenant-id":"test-tenant"}', user_agent 'corrently-fdw-test/1.0');
+
+SELECT COUNT(*) AS forecast_hours
+FROM fdw_corrently.gsi_prediction_tenant
+WHERE postal_code = '69168';
+-- Expected: same row count as Test 1 (INFO log shows the correlation id)
+
+\echo '\n=== Test 26: Per-table override of server options ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_prediction_override (
+    forecast_start_time timestamp with time zone,
+    green_energy_index numeric,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', api_url 'https://api.corrently.io/', max_retries '0');
+
+SELECT COUNT(*) AS forecast_hours
+FROM fdw_corrently.gsi_prediction_override
+WHERE postal_code = '69168';
+-- Expected: same row count as Test 1 (table options take precedence over the server)
+
+\echo '\n=== Test 27: Default postal_code / hours table options (no WHERE clause) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_hq (
+    forecast_start_time timestamp with time zone,
+    green_energy_index numeric,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', postal_code '69168', hours '24');
+
+SELECT COUNT(*) AS forecast_hours, MIN(postal_code) AS postal_code
+FROM fdw_corrently.gsi_hq;
+-- Expected: 24 rows for postal code 69168
+
+\echo '\n=== Test 28: Multi-site table (postal_codes / site_labels table options) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_fleet (
+    site_label text,
+    postal_code text,
+    forecast_start_time timestamp with time zone,
+    green_energy_index numeric
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', postal_codes '69168,10115,80331', site_labels 'HQ,Berlin,Munich', request_interval_ms '200');
+
+SELECT site_label, postal_code, COUNT(*) AS forecast_hours, MAX(green_energy_index) AS best_gsi
+FROM fdw_corrently.gsi_fleet
+GROUP BY site_label, postal_code
+ORDER BY site_label;
+-- Expected: 3 rows (Berlin/10115, HQ/69168, Munich/80331), ~113 hours each
+
+SELECT DISTINCT site_label, postal_code
+FROM fdw_corrently.gsi_fleet
+WHERE postal_code = '69168';
+-- Expected: 1 row (HQ, 69168), only that site is fetched
+
+SELECT COUNT(*) AS rows_outside_list
+FROM fdw_corrently.gsi_fleet
+WHERE postal_code = '01067';
+-- Expected: 0 (postal code not in postal_codes, no API request)
+
+\echo '\n=== Test 29: Option validation (all problems reported at once) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_misconfigured (
+    forecast_start_time timestamp with time zone,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_predicton', hours '200', max_retries '-1');
+
+SELECT * FROM fdw_corrently.gsi_misconfigured WHERE postal_code = '69168';
+-- Expected: ERROR CORRENTLY-E001 listing 3 problems (max_retries range, object "did you mean 'gsi_prediction'?", hours 1-113)
+
+\echo '\n=== Test 30: Stable error codes ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_prediction_bad_key (
+    forecast_start_time timestamp with time zone,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', api_key 'invalid-key');
+
+DO $$
+BEGIN
+    PERFORM * FROM fdw_corrently.gsi_prediction_bad_key WHERE postal_code = '69168';
+EXCEPTION WHEN OTHERS THEN
+    RAISE NOTICE 'auth error: %', SQLERRM LIKE '%CORRENTLY-E002%';
+END $$;
+-- Expected: NOTICE: auth error: t (API rejects the key with 401/403)
+
+SELECT * FROM fdw_corrently.gsi_prediction_bad_key WHERE postal_code = '69168';
+-- Expected: ERROR: CORRENTLY-E002 auth: Corrently API returned 401: invalid or expired API key, check api_key_id Vault secret
+
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.gsi_prediction_small_limit (
+    forecast_start_time timestamp with time zone,
+    postal_code text
+)
+SERVER corrently_server
+OPTIONS (object 'gsi_prediction', max_response_bytes '1024');
+
+SELECT * FROM fdw_corrently.gsi_prediction_small_limit WHERE postal_code = '69168';
+-- Expected: ERROR: CORRENTLY-E005 upstream: Corrently API response of ~50000 bytes exceeds max_response_bytes (1024), not parsed
+
+\echo '\n=== Test 31: Diagnostics in the stats metadata ==='
+SELECT
+    to_timestamp((metadata->>'last_success')::bigint) AS last_success,
+    metadata->>'last_error' AS last_error,
+    metadata->'scans' AS scans,
+    metadata->'requests' AS requests,
+    metadata->'latency' AS latency
+FROM extensions.wrappers_fdw_stats
+WHERE fdw_name = 'CorrentlyFdw';
+-- Expected: last_success within the test run, last_error from Test 30 (CORRENTLY-E002),
+--           requests counted per object (gsi_prediction, gsi_history, ...)
+--           latency buckets (le_1s, le_5s, ...) summing to the request count
+
+\echo '\n=== Test 32: Debug logging (log_level) ==='
+ALTER SERVER corrently_server OPTIONS (ADD log_level 'debug');
+SELECT COUNT(*) FROM fdw_corrently.gsi_prediction WHERE postal_code = '69168' AND green_energy_index > 50;
+-- Expected INFO lines: "[debug] quals pushed down: postal_code = '69168'; evaluated locally: green_energy_index > 50",
+--                      "[debug] request: GET ...token=***", "[debug] response: status 200, ... bytes in ... s"
+ALTER SERVER corrently_server OPTIONS (SET log_level 'warn');
+SELECT COUNT(*) FROM fdw_corrently.gsi_prediction WHERE postal_code = '69168';
+-- Expected: no INFO lines
+ALTER SERVER corrently_server OPTIONS (DROP log_level);
+
+\echo '\n=== Test 33: RowsOut accounting with LIMIT ==='
+SELECT rows_out AS rows_out_before FROM extensions.wrappers_fdw_stats WHERE fdw_name = 'CorrentlyFdw' \gset
+SELECT * FROM fdw_corrently.gsi_prediction WHERE postal_code = '69168' LIMIT 5;
+SELECT rows_out - :rows_out_before AS rows_out_delta, metadata->'last_scan' AS last_scan
+FROM extensions.wrappers_fdw_stats WHERE fdw_name = 'CorrentlyFdw';
+-- Expected: rows_out_delta = 5, last_scan = {"object": "gsi_prediction", "rows": 5, ...}
+
+\echo '\n=== Test 34: status object (health check) ==='
+CREATE FOREIGN TABLE IF NOT EXISTS fdw_corrently.status (
+    fdw_version text,
+    base_url text,
+    auth_mode text,
+    max_requests_per_scan bigint,
+    ping_postal_code text,
+    ping_ok boolean,
+    ping_status integer,
+    ping_latency_secs bigint,
+    ping_error text,
+    checked_at timestamp with time zone
+)
+SERVER corrently_server
+OPTIONS (object 'status', postal_code '69168');
+
+SELECT * FROM fdw_corrently.status;
+-- Expected: 1 row, ping_ok = true, ping_status = 200, ping_postal_code '69168', auth_mode 'vault (server)',
+--           base_url without credentials
+
 \timing off
 
 -- ============================================